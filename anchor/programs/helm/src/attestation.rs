@@ -0,0 +1,124 @@
+// File: src/attestation.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ ed25519_program, sysvar::instructions::get_instruction_relative };
+
+use crate::errors::HelmError;
+
+// Domain tags keep an attestation for one action from being replayed for another
+pub const VERIFY_ACCOUNT_DOMAIN: &[u8] = b"helm:verify-account";
+pub const RECLAIM_ACCOUNT_DOMAIN: &[u8] = b"helm:reclaim-account";
+pub const UPDATE_HANDLE_DOMAIN: &[u8] = b"helm:update-handle";
+
+// Layout of the ed25519 precompile instruction data
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+// Offsets pointing at the precompile instruction itself
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Message the attestation oracle signs to vouch for `owner` controlling `twitter_id`,
+/// currently known as `handle`
+pub fn verification_message(
+    twitter_account: &Pubkey,
+    twitter_id: &str,
+    owner: &Pubkey,
+    handle: &str
+) -> Vec<u8> {
+    [
+        VERIFY_ACCOUNT_DOMAIN,
+        twitter_account.as_ref(),
+        twitter_id.as_bytes(),
+        owner.as_ref(),
+        handle.as_bytes(),
+    ].concat()
+}
+
+/// Message the attestation oracle signs to hand `twitter_id`, currently known as `handle`,
+/// over to its rightful `claimant`. The member epoch and expiry keep it from being replayed
+/// once used or stale.
+pub fn reclaim_message(
+    twitter_account: &Pubkey,
+    twitter_id: &str,
+    claimant: &Pubkey,
+    member_epoch: u32,
+    expires_at: i64,
+    handle: &str
+) -> Vec<u8> {
+    [
+        RECLAIM_ACCOUNT_DOMAIN,
+        twitter_account.as_ref(),
+        twitter_id.as_bytes(),
+        claimant.as_ref(),
+        &member_epoch.to_le_bytes(),
+        &expires_at.to_le_bytes(),
+        handle.as_bytes(),
+    ].concat()
+}
+
+/// Message the attestation oracle signs to confirm `twitter_id` is now known as `handle`
+pub fn handle_message(twitter_account: &Pubkey, twitter_id: &str, handle: &str) -> Vec<u8> {
+    [
+        UPDATE_HANDLE_DOMAIN,
+        twitter_account.as_ref(),
+        &(twitter_id.len() as u32).to_le_bytes(),
+        twitter_id.as_bytes(),
+        handle.as_bytes(),
+    ].concat()
+}
+
+/// Checks that the instruction right before this one is an ed25519 signature
+/// verification of `message` by one of `signers`. The precompile itself fails
+/// the transaction on a bad signature, so only its inputs need checking here.
+pub fn verify_attestation(
+    instructions_sysvar: &AccountInfo,
+    signers: &[Pubkey],
+    message: &[u8]
+) -> Result<()> {
+    let ix = get_instruction_relative(-1, instructions_sysvar).map_err(
+        |_| HelmError::MissingAttestation
+    )?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, HelmError::MissingAttestation);
+    require!(ix.accounts.is_empty(), HelmError::InvalidAttestation);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE && data[0] == 1,
+        HelmError::InvalidAttestation
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = SIGNATURE_OFFSETS_START;
+    let signature_offset = read_u16(offsets) as usize;
+    let signature_ix_index = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4) as usize;
+    let pubkey_ix_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_ix_index = read_u16(offsets + 12);
+
+    // All signed data must live inside the precompile instruction we inspect
+    require!(
+        signature_ix_index == CURRENT_INSTRUCTION &&
+            pubkey_ix_index == CURRENT_INSTRUCTION &&
+            message_ix_index == CURRENT_INSTRUCTION,
+        HelmError::InvalidAttestation
+    );
+    require!(
+        signature_offset + SIGNATURE_SIZE <= data.len() &&
+            pubkey_offset + PUBKEY_SIZE <= data.len() &&
+            message_offset + message_size <= data.len(),
+        HelmError::InvalidAttestation
+    );
+
+    let signer = Pubkey::try_from(&data[pubkey_offset..pubkey_offset + PUBKEY_SIZE]).map_err(
+        |_| HelmError::InvalidAttestation
+    )?;
+    require!(signers.contains(&signer), HelmError::InvalidAttestation);
+    require!(
+        &data[message_offset..message_offset + message_size] == message,
+        HelmError::InvalidAttestation
+    );
+
+    Ok(())
+}
@@ -1,77 +1,187 @@
 // File: src/constants.rs
 use anchor_lang::prelude::*;
 
-// Program Authority
+// Program Authority; localnet builds trust the test keypair in tests/fixtures instead
+#[cfg(not(feature = "localnet"))]
 pub const SERVICE_AUTHORITY: Pubkey = pubkey!("Hem9xTjkFZwX2XaJ7oHpGmYzL6Pb58GfzWxr2TmU2PZ");
+#[cfg(feature = "localnet")]
+pub const SERVICE_AUTHORITY: Pubkey = pubkey!("8KGNHwiZCBBbLfiwcWsNUv2onrvpNMGWu8fqhFnkLBf3");
 
 // Account Sizes and Limits
-pub const MAX_ADMINS: usize = 10;
-pub const MAX_CREATORS: usize = 10;
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 pub const MAX_TWITTER_ID_LENGTH: usize = 64;
 pub const MAX_TWITTER_HANDLE_LENGTH: usize = 32;
+pub const MAX_HANDLE_HISTORY: usize = 5;
 pub const MAX_REJECTION_REASON_LENGTH: usize = 256;
 pub const MAX_FAILURE_REASON_LENGTH: usize = 256;
 pub const MIN_REQUIRED_APPROVALS: u8 = 1;
 pub const MAX_REQUIRED_APPROVALS: u8 = 10;
+pub const MAX_APPROVAL_GROUPS: usize = 4;
+pub const MAX_APPROVAL_GROUP_NAME_LENGTH: usize = 32;
+pub const MAX_GROUP_MEMBERS: usize = 10;
+pub const MAX_EXPIRING_ADMINS: usize = 32; // Admins with a membership expiry at any one time
+pub const MAX_CONTENT_APPROVALS: usize = 20; // Approvals stored per content; policies may not need more
+pub const MIN_PUBLISH_ATTEMPTS: u8 = 1;
+pub const MAX_PUBLISH_ATTEMPTS: u8 = 10;
 pub const MAX_THREAD_LENGTH: u8 = 50; // Maximum number of tweets in a thread
+pub const MAX_PUBLISHED_TWEET_IDS: usize = MAX_THREAD_LENGTH as usize; // One ID per posted tweet
+pub const MIN_POLL_OPTIONS: usize = 2;
+pub const MAX_POLL_OPTIONS: usize = 4;
+pub const MIN_POLL_DURATION_MINUTES: u16 = 5;
+pub const MAX_POLL_DURATION_MINUTES: u16 = 7 * 24 * 60; // 7 days
+pub const MAX_MEDIA_ITEMS: usize = 4;
+pub const MAX_EXPIRY_CRANK_FEE: u64 = 1_000_000; // Lamports; well below the rent of a content account
 
 // Space Calculations for Account Sizes
+pub const APPROVAL_POLICY_SIZE: usize =
+    1 + // policy enum discriminator
+    4 + // groups vec length prefix (largest variant)
+    MAX_APPROVAL_GROUPS *
+        (4 +
+            MAX_APPROVAL_GROUP_NAME_LENGTH + // group name with length prefix
+            4 +
+            32 * MAX_GROUP_MEMBERS); // group members vec with length prefix
+
 pub const TWITTER_ACCOUNT_SIZE: usize =
     ANCHOR_DISCRIMINATOR + // discriminator (8 bytes)
     32 + // owner pubkey
+    33 + // pending_owner Option<Pubkey>
+    9 + // pending_owner_expires_at Option<i64>
     4 +
     MAX_TWITTER_ID_LENGTH + // twitter_id string (max) with length prefix
     4 +
     MAX_TWITTER_HANDLE_LENGTH + // twitter_handle string (max) with length prefix
-    1 + // required_approvals
+    4 +
+    MAX_HANDLE_HISTORY * (4 + MAX_TWITTER_HANDLE_LENGTH + 8) + // handle_history vec with length prefix
+    APPROVAL_POLICY_SIZE + // approval_policy
+    1 + // max_publish_attempts
+    1 + // reschedule_policy enum
+    8 + // expiry_crank_fee
+    8 + // content_count
+    4 + // open_content_count
+    4 + // content_record_count
+    4 + // revision_record_count
+    4 + // member_count
+    4 + // invite_count
+    4 + // admin_count
+    4 +
+    MAX_EXPIRING_ADMINS * 8 + // admin_expiries vec with length prefix
+    4 + // member_epoch
     1 + // is_verified
     8 + // created_at
     1; // bump
 
-pub const ADMIN_LIST_SIZE: usize =
+pub const MEMBER_SIZE: usize =
     ANCHOR_DISCRIMINATOR + // discriminator
     32 + // twitter_account pubkey
-    4 +
-    32 * MAX_ADMINS + // admins vec (account for vec length prefix)
-    32 + // authority
+    32 + // wallet pubkey
+    1 + // roles bitmask
+    4 + // epoch
+    32 + // added_by
+    8 + // added_at
+    9 + // expires_at Option<i64>
+    32 + // payer pubkey
     1; // bump
 
-pub const CREATOR_LIST_SIZE: usize =
+pub const INVITE_SIZE: usize =
     ANCHOR_DISCRIMINATOR + // discriminator
     32 + // twitter_account pubkey
-    4 +
-    32 * MAX_CREATORS + // creators vec (account for vec length prefix)
-    32 + // authority
+    32 + // invitee pubkey
+    1 + // roles bitmask
+    33 + // code_hash Option<[u8; 32]>
+    4 + // epoch
+    32 + // invited_by
+    8 + // created_at
+    8 + // expires_at
     1; // bump
 
+pub const CONTENT_TYPE_SIZE: usize =
+    1 + // variant discriminator
+    4 +
+    32 * MAX_POLL_OPTIONS + // poll option_hashes vec with length prefix (largest variant)
+    2; // poll duration_minutes
+
 pub const BASE_CONTENT_SIZE: usize =
     ANCHOR_DISCRIMINATOR + // discriminator
     32 + // twitter_account pubkey
+    8 + // sequence
     32 + // author pubkey
-    2 + // content_type enum (1 byte discriminator + 1 byte for value)
+    32 + // payer pubkey
+    CONTENT_TYPE_SIZE + // content_type enum
     32 + // content_hash
+    2 + // revision
     9 + // scheduled_for Option<i64> (1 byte for Option + 8 bytes for i64)
     2 + // status enum (1 byte discriminator + 1 byte for value)
     4 +
-    32 * MAX_ADMINS + // approvals vec with length prefix
+    32 * MAX_CONTENT_APPROVALS + // approvals vec with length prefix
+    4 + // approvals_epoch
     4 +
     MAX_REJECTION_REASON_LENGTH + // rejection_reason Option<String> with length prefix
     4 +
     MAX_FAILURE_REASON_LENGTH + // failure_reason Option<String> with length prefix
+    4 +
+    8 * MAX_PUBLISHED_TWEET_IDS + // published_tweet_ids vec with length prefix
+    8 + // published_items bitmap
+    9 + // published_at Option<i64>
+    1 + // publish_attempts
+    9 + // next_retry_at Option<i64>
+    33 + // rescheduled_by Option<Pubkey>
     8 + // created_at
     8 + // updated_at
     1; // bump
 
+pub const CONTENT_REVISION_SIZE: usize =
+    ANCHOR_DISCRIMINATOR + // discriminator
+    32 + // content pubkey
+    32 + // twitter_account pubkey
+    2 + // revision
+    CONTENT_TYPE_SIZE + // content_type enum
+    32 + // content_hash
+    1 +
+    4 +
+    MAX_REJECTION_REASON_LENGTH + // rejection_reason Option<String> with length prefix
+    32 + // revised_by
+    8 + // created_at
+    1; // bump
+
+pub const HANDLE_INDEX_SIZE: usize =
+    ANCHOR_DISCRIMINATOR + // discriminator
+    32 + // twitter_account pubkey
+    4 +
+    MAX_TWITTER_HANDLE_LENGTH + // handle string with length prefix
+    1; // bump
+
+pub const ATTESTATION_CONFIG_SIZE: usize =
+    ANCHOR_DISCRIMINATOR + // discriminator
+    33 + // oracle Option<Pubkey>
+    8 + // updated_at
+    1; // bump
+
 // PDA Seeds
 pub const TWITTER_ACCOUNT_SEED: &[u8] = b"twitter-account";
-pub const ADMIN_LIST_SEED: &[u8] = b"admin-list";
-pub const CREATOR_LIST_SEED: &[u8] = b"creator-list";
+pub const MEMBER_SEED: &[u8] = b"member";
+pub const INVITE_SEED: &[u8] = b"invite";
 pub const CONTENT_SEED: &[u8] = b"content";
+pub const CONTENT_REVISION_SEED: &[u8] = b"content-revision";
+pub const ATTESTATION_CONFIG_SEED: &[u8] = b"attestation-config";
+pub const HANDLE_INDEX_SEED: &[u8] = b"handle-index";
+
+// Member Roles (bitmask)
+pub const ROLE_OWNER: u8 = 1 << 0;
+pub const ROLE_ADMIN: u8 = 1 << 1;
+pub const ROLE_CREATOR: u8 = 1 << 2;
+pub const ROLE_PUBLISHER: u8 = 1 << 3;
+pub const ROLE_VIEWER: u8 = 1 << 4;
+// Every role except Owner, which only moves with ownership itself
+pub const ASSIGNABLE_ROLES: u8 = ROLE_ADMIN | ROLE_CREATOR | ROLE_PUBLISHER | ROLE_VIEWER;
 
 // Time Constants (in seconds)
 pub const MIN_SCHEDULE_DELAY: i64 = 300; // 5 minutes minimum delay for scheduling
 pub const MAX_SCHEDULE_DELAY: i64 = 30 * 24 * 60 * 60; // 30 days maximum scheduling window
+pub const DEFAULT_RETRY_DELAY: i64 = 60; // 1 minute before a failed publication may be retried
+pub const MAX_OWNERSHIP_PROPOSAL_WINDOW: i64 = 7 * 24 * 60 * 60; // 7 days to accept an ownership transfer
+pub const EXPIRATION_GRACE_PERIOD: i64 = 24 * 60 * 60; // 1 day past the schedule before content can expire
 
 // Default Values
 pub const DEFAULT_REQUIRED_APPROVALS: u8 = 3;
+pub const DEFAULT_MAX_PUBLISH_ATTEMPTS: u8 = 3;
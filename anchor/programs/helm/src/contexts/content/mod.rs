@@ -1,10 +1,18 @@
 // File: src/contexts/content/mod.rs
 
 use anchor_lang::prelude::*;
-use crate::constants::{ ADMIN_LIST_SEED, TWITTER_ACCOUNT_SEED, CONTENT_SEED, BASE_CONTENT_SIZE };
-use crate::state::{ Content, TwitterAccount, AdminList };
+use crate::constants::{
+    MEMBER_SEED,
+    TWITTER_ACCOUNT_SEED,
+    CONTENT_SEED,
+    BASE_CONTENT_SIZE,
+    CONTENT_REVISION_SEED,
+    CONTENT_REVISION_SIZE,
+    SERVICE_AUTHORITY,
+};
+use crate::state::{ Content, ContentRevision, Member, TwitterAccount };
 use crate::errors::HelmError;
-use crate::enums::ContentType;
+use crate::enums::ContentStatus;
 
 // Base context that others will derive from
 pub trait ContentValidator {
@@ -14,8 +22,8 @@ pub trait ContentValidator {
     fn validate_content_uniqueness(&self) -> Result<()>;
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(content_type: ContentType, content_hash: [u8; 32])]
 pub struct SubmitContentAction<'info> {
     #[account(
         init,
@@ -24,14 +32,14 @@ pub struct SubmitContentAction<'info> {
         seeds = [
             CONTENT_SEED,
             twitter_account.key().as_ref(),
-            authority.key().as_ref(),
-            content_hash.as_ref(),
+            twitter_account.content_count.to_le_bytes().as_ref(),
         ],
         bump
     )]
     pub content: Account<'info, Content>,
 
     #[account(
+        mut,
         seeds = [
             TWITTER_ACCOUNT_SEED,
             twitter_account.twitter_id.as_bytes(),
@@ -42,17 +50,19 @@ pub struct SubmitContentAction<'info> {
     pub twitter_account: Account<'info, TwitterAccount>,
 
     #[account(
-        seeds = [ADMIN_LIST_SEED, twitter_account.twitter_id.as_bytes()],
-        bump = admin_list.bump,
-        constraint = admin_list.admins.contains(&authority.key()) @ HelmError::Unauthorized
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.can_create_content(twitter_account.member_epoch) @ HelmError::Unauthorized
     )]
-    pub admin_list: Account<'info, AdminList>,
+    pub member: Account<'info, Member>,
 
+    /// Either an admin or a creator of the account; becomes the content author
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ContentAction<'info> {
     #[account(
@@ -60,8 +70,7 @@ pub struct ContentAction<'info> {
         seeds = [
             CONTENT_SEED,
             content.twitter_account.as_ref(),
-            content.author.as_ref(), 
-            content.content_hash.as_ref()
+            content.sequence.to_le_bytes().as_ref()
         ],
         bump = content.bump,
         constraint = !content.is_terminal() @ HelmError::ContentInTerminalState
@@ -69,6 +78,7 @@ pub struct ContentAction<'info> {
     pub content: Account<'info, Content>,
 
     #[account(
+        mut,
         seeds = [
             TWITTER_ACCOUNT_SEED,
             twitter_account.twitter_id.as_bytes(),
@@ -80,13 +90,297 @@ pub struct ContentAction<'info> {
     pub twitter_account: Account<'info, TwitterAccount>,
 
     #[account(
-        seeds = [ADMIN_LIST_SEED, twitter_account.twitter_id.as_bytes()],
-        bump = admin_list.bump,
-        constraint = admin_list.admins.contains(&authority.key()) @ HelmError::Unauthorized
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.is_admin(twitter_account.member_epoch) @ HelmError::Unauthorized
+    )]
+    pub member: Account<'info, Member>,
+
+    pub authority: Signer<'info>,
+    // Remaining accounts: when approving, the Member PDA of every earlier approver
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DraftAction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            CONTENT_SEED,
+            content.twitter_account.as_ref(),
+            content.sequence.to_le_bytes().as_ref()
+        ],
+        bump = content.bump,
+        constraint = content.status == ContentStatus::Draft @ HelmError::InvalidContentStatus
+    )]
+    pub content: Account<'info, Content>,
+
+    #[account(
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = content.twitter_account == twitter_account.key() @ HelmError::InvalidTwitterAccount,
+        constraint = twitter_account.is_verified @ HelmError::TwitterAccountNotVerified
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    #[account(
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.can_create_content(twitter_account.member_epoch) @ HelmError::Unauthorized
+    )]
+    pub member: Account<'info, Member>,
+
+    /// Only the draft's author, while still an admin or creator of the account
+    #[account(
+        constraint = content.author == authority.key() @ HelmError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReviseContent<'info> {
+    #[account(
+        mut,
+        seeds = [
+            CONTENT_SEED,
+            content.twitter_account.as_ref(),
+            content.sequence.to_le_bytes().as_ref()
+        ],
+        bump = content.bump,
+        constraint = content.status == ContentStatus::Rejected @ HelmError::InvalidContentStatus
+    )]
+    pub content: Account<'info, Content>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CONTENT_REVISION_SIZE,
+        seeds = [
+            CONTENT_REVISION_SEED,
+            content.key().as_ref(),
+            content.revision.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub content_revision: Account<'info, ContentRevision>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = content.twitter_account == twitter_account.key() @ HelmError::InvalidTwitterAccount,
+        constraint = twitter_account.is_verified @ HelmError::TwitterAccountNotVerified
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    #[account(
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), authority.key().as_ref()],
+        bump = member.bump,
+        constraint = member.can_create_content(twitter_account.member_epoch) @ HelmError::Unauthorized
+    )]
+    pub member: Account<'info, Member>,
+
+    /// Only the content's author, while still an admin or creator of the account
+    #[account(
+        mut,
+        constraint = content.author == authority.key() @ HelmError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ServiceContentAction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            CONTENT_SEED,
+            content.twitter_account.as_ref(),
+            content.sequence.to_le_bytes().as_ref()
+        ],
+        bump = content.bump,
+        constraint = content.twitter_account == twitter_account.key() @ HelmError::InvalidTwitterAccount
+    )]
+    pub content: Account<'info, Content>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = twitter_account.is_verified @ HelmError::TwitterAccountNotVerified
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    #[account(constraint = authority.key() == SERVICE_AUTHORITY @ HelmError::Unauthorized)]
+    pub authority: Signer<'info>,
+    // Remaining accounts: when publishing, the Member PDA of every approver
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RetryContentAction<'info> {
+    #[account(
+        mut,
+        seeds = [
+            CONTENT_SEED,
+            content.twitter_account.as_ref(),
+            content.sequence.to_le_bytes().as_ref()
+        ],
+        bump = content.bump,
+        constraint = content.twitter_account == twitter_account.key() @ HelmError::InvalidTwitterAccount
+    )]
+    pub content: Account<'info, Content>,
+
+    #[account(
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = twitter_account.is_verified @ HelmError::TwitterAccountNotVerified
     )]
-    pub admin_list: Account<'info, AdminList>,
+    pub twitter_account: Account<'info, TwitterAccount>,
 
+    /// Omitted when the service authority retries
+    #[account(
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), authority.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Option<Account<'info, Member>>,
+
+    /// Either the service authority or one of the account admins
+    #[account(
+        constraint = authority.key() == SERVICE_AUTHORITY ||
+        member.as_ref().is_some_and(|m| m.is_admin(twitter_account.member_epoch)) @ HelmError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseContent<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            CONTENT_SEED,
+            content.twitter_account.as_ref(),
+            content.sequence.to_le_bytes().as_ref()
+        ],
+        bump = content.bump,
+        // Failed content can still be retried, so it has to be canceled first
+        constraint = content.is_terminal() @ HelmError::ContentNotTerminal
+    )]
+    pub content: Account<'info, Content>,
+
+    /// CHECK: Receives the rent; must be the wallet that paid for the content
+    #[account(mut, address = content.payer @ HelmError::Unauthorized)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// The payer, or an admin of the account
+    #[account(
+        constraint = authority.key() == content.payer ||
+        member.as_ref().is_some_and(|m| m.is_admin(twitter_account.member_epoch)) @ HelmError::Unauthorized
+    )]
     pub authority: Signer<'info>,
+
+    /// Tallies the content records left; the account cannot deregister while any remain
+    #[account(
+        mut,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.key() == content.twitter_account @ HelmError::InvalidTwitterAccount
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// Omitted when the payer closes
+    #[account(
+        seeds = [MEMBER_SEED, content.twitter_account.as_ref(), authority.key().as_ref()],
+        bump = member.bump
+    )]
+    pub member: Option<Account<'info, Member>>,
+}
+
+/// Permissionless: once content is finished its archived revisions go back to
+/// whoever paid for them
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseContentRevision<'info> {
+    #[account(
+        mut,
+        close = revised_by,
+        seeds = [
+            CONTENT_REVISION_SEED,
+            content_revision.content.as_ref(),
+            content_revision.revision.to_le_bytes().as_ref(),
+        ],
+        bump = content_revision.bump
+    )]
+    pub content_revision: Account<'info, ContentRevision>,
+
+    /// CHECK: The revised content; either already closed or in a terminal status
+    #[account(address = content_revision.content)]
+    pub content: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.key() == content_revision.twitter_account @ HelmError::InvalidTwitterAccount
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// CHECK: Receives the rent; must be the author who archived the revision
+    #[account(mut, address = content_revision.revised_by @ HelmError::Unauthorized)]
+    pub revised_by: UncheckedAccount<'info>,
+
+    pub caller: Signer<'info>,
+}
+
+/// Permissionless: anyone may expire content once its schedule and grace period have passed
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExpireContent<'info> {
+    #[account(
+        mut,
+        seeds = [
+            CONTENT_SEED,
+            content.twitter_account.as_ref(),
+            content.sequence.to_le_bytes().as_ref()
+        ],
+        bump = content.bump,
+        constraint = content.twitter_account == twitter_account.key() @ HelmError::InvalidTwitterAccount
+    )]
+    pub content: Account<'info, Content>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// CHECK: Receives the remaining rent when a crank fee is configured
+    #[account(mut, address = content.payer @ HelmError::Unauthorized)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Receives the crank fee, if any
+    #[account(mut)]
+    pub caller: Signer<'info>,
 }
 
 impl<'info> ContentValidator for ContentAction<'info> {
@@ -95,7 +389,7 @@ impl<'info> ContentValidator for ContentAction<'info> {
         Ok(())
     }
     fn validate_authority(&self) -> Result<()> {
-        require!(self.admin_list.admins.contains(&self.authority.key()), HelmError::Unauthorized);
+        require!(self.member.is_admin(self.twitter_account.member_epoch), HelmError::Unauthorized);
         Ok(())
     }
 
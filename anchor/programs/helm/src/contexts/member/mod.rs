@@ -0,0 +1,281 @@
+// File: src/contexts/member/mod.rs
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{
+        ASSIGNABLE_ROLES,
+        INVITE_SEED,
+        INVITE_SIZE,
+        MEMBER_SEED,
+        MEMBER_SIZE,
+        ROLE_ADMIN,
+        ROLE_CREATOR,
+        TWITTER_ACCOUNT_SEED,
+    },
+    errors::HelmError,
+    state::{ Invite, Member, TwitterAccount },
+};
+
+pub trait MemberValidator {
+    fn validate_roles(&self, roles: u8) -> Result<()>;
+    fn validate_expiry(&self, wallet: &Pubkey, expires_at: Option<i64>) -> Result<()>;
+    fn validate_roles_absent(&self, roles: u8) -> Result<()>;
+    fn validate_roles_present(&self, roles: u8) -> Result<()>;
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct ManageMember<'info> {
+    /// Created on the first grant and closed once its last role is revoked
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = MEMBER_SIZE,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner == owner.key() @ HelmError::Unauthorized,
+        constraint = twitter_account.is_verified @ HelmError::TwitterAccountNotVerified
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Refunded the record's rent if a revoke leaves it without any role; must be
+    /// the wallet that paid for it. Grants may pass none.
+    #[account(mut)]
+    pub member_payer: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: anyone may prune memberships past their expiry
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PruneExpiredMembers<'info> {
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    pub caller: Signer<'info>,
+    // Remaining accounts: each expired Member PDA to prune, followed by the wallet that
+    // paid its rent
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(invitee: Pubkey)]
+pub struct CreateInvite<'info> {
+    /// One pending invite per wallet; the owner funds it until it is accepted or revoked
+    #[account(
+        init,
+        payer = owner,
+        space = INVITE_SIZE,
+        seeds = [INVITE_SEED, twitter_account.key().as_ref(), invitee.as_ref()],
+        bump
+    )]
+    pub invite: Account<'info, Invite>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner == owner.key() @ HelmError::Unauthorized,
+        constraint = twitter_account.is_verified @ HelmError::TwitterAccountNotVerified
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptInvite<'info> {
+    /// Rent goes back to whoever created and funded the invite
+    #[account(
+        mut,
+        close = invited_by,
+        seeds = [INVITE_SEED, twitter_account.key().as_ref(), invitee.key().as_ref()],
+        bump = invite.bump
+    )]
+    pub invite: Account<'info, Invite>,
+
+    /// The invitee pays for its own membership record
+    #[account(
+        init_if_needed,
+        payer = invitee,
+        space = MEMBER_SIZE,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), invitee.key().as_ref()],
+        bump
+    )]
+    pub member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// CHECK: Receives the invite's rent
+    #[account(mut, address = invite.invited_by @ HelmError::InvalidInvitePayer)]
+    pub invited_by: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub invitee: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeInvite<'info> {
+    /// Rent goes back to whoever created the invite, even after an ownership transfer
+    #[account(
+        mut,
+        close = invited_by,
+        seeds = [INVITE_SEED, twitter_account.key().as_ref(), invite.invitee.as_ref()],
+        bump = invite.bump
+    )]
+    pub invite: Account<'info, Invite>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner == owner.key() @ HelmError::Unauthorized
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Receives the invite's rent
+    #[account(mut, address = invite.invited_by @ HelmError::InvalidInvitePayer)]
+    pub invited_by: UncheckedAccount<'info>,
+}
+
+/// Signed by the new key plus either the old key or the owner
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RotateAdminKey<'info> {
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), old_wallet.key().as_ref()],
+        bump = old_member.bump,
+        constraint = old_member.is_admin(twitter_account.member_epoch) @ HelmError::AdminDoesNotExist
+    )]
+    pub old_member: Account<'info, Member>,
+
+    /// Takes over the old record's roles and expiry; the new key pays for it
+    #[account(
+        init_if_needed,
+        payer = new_wallet,
+        space = MEMBER_SIZE,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), new_wallet.key().as_ref()],
+        bump
+    )]
+    pub new_member: Account<'info, Member>,
+
+    #[account(
+        mut,
+        seeds = [
+            TWITTER_ACCOUNT_SEED,
+            twitter_account.twitter_id.as_bytes(),
+        ],
+        bump = twitter_account.bump,
+        constraint = old_wallet.key() != twitter_account.owner @ HelmError::CannotRotateOwnerKey
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// CHECK: The key being retired; only its member PDA is read
+    pub old_wallet: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub new_wallet: Signer<'info>,
+
+    #[account(
+        constraint = authority.key() == old_wallet.key() ||
+        authority.key() == twitter_account.owner @ HelmError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Refunded the old membership record's rent; whoever paid for that record
+    #[account(mut, address = old_member.payer @ HelmError::InvalidMemberPayer)]
+    pub old_member_payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    // Remaining accounts: open Content whose approvals move to the new key
+}
+
+impl<'info> MemberValidator for ManageMember<'info> {
+    fn validate_roles(&self, roles: u8) -> Result<()> {
+        require!(roles != 0 && roles & !ASSIGNABLE_ROLES == 0, HelmError::InvalidRole);
+        Ok(())
+    }
+
+    fn validate_expiry(&self, wallet: &Pubkey, expires_at: Option<i64>) -> Result<()> {
+        if let Some(expires_at) = expires_at {
+            // The owner's own membership must never lapse
+            require!(*wallet != self.twitter_account.owner, HelmError::InvalidMembershipExpiry);
+            require!(
+                expires_at > Clock::get()?.unix_timestamp,
+                HelmError::InvalidMembershipExpiry
+            );
+        }
+        Ok(())
+    }
+
+    fn validate_roles_absent(&self, roles: u8) -> Result<()> {
+        require_roles_absent(self.current_roles(), roles)
+    }
+
+    fn validate_roles_present(&self, roles: u8) -> Result<()> {
+        let missing = roles & !self.current_roles();
+        require!(missing & ROLE_ADMIN == 0, HelmError::AdminDoesNotExist);
+        require!(missing & ROLE_CREATOR == 0, HelmError::CreatorDoesNotExist);
+        require!(missing == 0, HelmError::RoleNotGranted);
+        Ok(())
+    }
+}
+
+impl<'info> ManageMember<'info> {
+    fn current_roles(&self) -> u8 {
+        self.member.active_roles(self.twitter_account.member_epoch)
+    }
+}
+
+/// Shared by direct grants and accepted invites
+pub fn require_roles_absent(current: u8, roles: u8) -> Result<()> {
+    require!(current & ROLE_ADMIN & roles == 0, HelmError::AdminAlreadyExists);
+    require!(current & ROLE_CREATOR & roles == 0, HelmError::CreatorAlreadyExists);
+    require!(current & roles == 0, HelmError::RoleAlreadyGranted);
+    Ok(())
+}
@@ -1,11 +1,11 @@
 // /src/contexts/mod.rs
 
 pub mod twitter;
-pub mod admin;
+pub mod member;
 pub mod content;
-pub mod creator;
+pub mod service;
 
 pub use twitter::*;
-pub use admin::*;
+pub use member::*;
 pub use content::*;
-pub use creator::*;
+pub use service::*;
@@ -0,0 +1,28 @@
+// File: src/contexts/service/mod.rs
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ ATTESTATION_CONFIG_SEED, ATTESTATION_CONFIG_SIZE, SERVICE_AUTHORITY },
+    errors::HelmError,
+    state::AttestationConfig,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAttestationOracle<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ATTESTATION_CONFIG_SIZE,
+        seeds = [ATTESTATION_CONFIG_SEED],
+        bump
+    )]
+    pub attestation_config: Account<'info, AttestationConfig>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == SERVICE_AUTHORITY @ HelmError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -2,22 +2,35 @@
 use anchor_lang::prelude::*;
 use crate::{
     constants::{
-        ADMIN_LIST_SEED,
-        CREATOR_LIST_SEED,
+        ATTESTATION_CONFIG_SEED,
+        HANDLE_INDEX_SEED,
+        HANDLE_INDEX_SIZE,
+        MEMBER_SEED,
+        MEMBER_SIZE,
         TWITTER_ACCOUNT_SEED,
         TWITTER_ACCOUNT_SIZE,
-        ADMIN_LIST_SIZE,
-        CREATOR_LIST_SIZE,
     },
     errors::HelmError,
-    state::{ AdminList, CreatorList, TwitterAccount },
+    state::{ AttestationConfig, HandleIndex, Member, TwitterAccount },
 };
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 
 pub trait TwitterAccountValidator {
-    fn validate_handle_format(&self, handle: &str) -> Result<()>;
-    fn validate_id_format(&self, id: &str) -> Result<()>;
+    fn validate_handle_format(&self, handle: &str) -> Result<()> {
+        require!(
+            handle.len() <= 15 && handle.chars().all(|c| c.is_alphanumeric() || c == '_'),
+            HelmError::InvalidTwitterHandle
+        );
+        Ok(())
+    }
+
+    fn validate_id_format(&self, id: &str) -> Result<()> {
+        require!(id.chars().all(char::is_numeric), HelmError::InvalidTwitterId);
+        Ok(())
+    }
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(twitter_id: String, twitter_handle: String)]
 pub struct RegisterTwitterAccount<'info> {
@@ -30,47 +43,78 @@ pub struct RegisterTwitterAccount<'info> {
     )]
     pub twitter_account: Account<'info, TwitterAccount>,
 
+    /// The owner's own membership, holding the Owner and Admin roles
     #[account(
         init,
         payer = owner,
-        space = ADMIN_LIST_SIZE,
-        seeds = [ADMIN_LIST_SEED, twitter_id.as_bytes()],
+        space = MEMBER_SIZE,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), owner.key().as_ref()],
         bump
     )]
-    pub admin_list: Account<'info, AdminList>,
+    pub owner_member: Account<'info, Member>,
 
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TwitterAccountValidator for RegisterTwitterAccount<'info> {}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VerifyTwitterAccount<'info> {
     #[account(
-        init,
+        mut,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner == owner.key() @ HelmError::Unauthorized,
+        constraint = !twitter_account.is_verified @ HelmError::AlreadyVerified
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// Reverse lookup from the lowercase handle, only claimed once the service vouches
+    /// for the handle; an index left behind by an account that no longer uses it is taken over
+    #[account(
+        init_if_needed,
         payer = owner,
-        space = CREATOR_LIST_SIZE,
-        seeds = [CREATOR_LIST_SEED, twitter_id.as_bytes()],
+        space = HANDLE_INDEX_SIZE,
+        seeds = [HANDLE_INDEX_SEED, twitter_account.twitter_handle.to_lowercase().as_bytes()],
         bump
     )]
-    pub creator_list: Account<'info, CreatorList>,
+    pub handle_index: Account<'info, HandleIndex>,
 
-    #[account(mut)]
+    /// Only needed when the attestation comes from the configured oracle
+    #[account(seeds = [ATTESTATION_CONFIG_SEED], bump = attestation_config.bump)]
+    pub attestation_config: Option<Account<'info, AttestationConfig>>,
+
+    #[account(mut, constraint = owner.key() == twitter_account.owner @ HelmError::Unauthorized)]
     pub owner: Signer<'info>,
 
+    /// CHECK: Address is checked against the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
-impl<'info> TwitterAccountValidator for RegisterTwitterAccount<'info> {
-    fn validate_handle_format(&self, handle: &str) -> Result<()> {
-        require!(
-            handle.len() <= 15 && handle.chars().all(|c| (c.is_alphanumeric() || c == '_')),
-            HelmError::InvalidTwitterHandle
-        );
-        Ok(())
-    }
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateTwitterAccount<'info> {
+    #[account(
+        mut,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner == owner.key() @ HelmError::Unauthorized
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
 
-    fn validate_id_format(&self, id: &str) -> Result<()> {
-        require!(id.chars().all(char::is_numeric), HelmError::InvalidTwitterId);
-        Ok(())
-    }
+    pub owner: Signer<'info>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-pub struct VerifyTwitterAccount<'info> {
+pub struct UpdateApprovalPolicy<'info> {
     #[account(
         mut,
         seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
@@ -79,6 +123,180 @@ pub struct VerifyTwitterAccount<'info> {
     )]
     pub twitter_account: Account<'info, TwitterAccount>,
 
-    #[account(constraint = owner.key() == twitter_account.owner @ HelmError::Unauthorized)]
     pub owner: Signer<'info>,
+    // Remaining accounts: the Member PDA of every wallet named in an approval group
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimTwitterAccount<'info> {
+    #[account(
+        mut,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner != claimant.key() @ HelmError::Unauthorized
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// Reused if the claimant was a member before; the epoch bump resets it either way
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = MEMBER_SIZE,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claimant_member: Account<'info, Member>,
+
+    /// The attestation vouches for the handle as well, so the reverse lookup is claimed
+    /// for the claimant just as verification would
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = HANDLE_INDEX_SIZE,
+        seeds = [HANDLE_INDEX_SEED, twitter_account.twitter_handle.to_lowercase().as_bytes()],
+        bump
+    )]
+    pub handle_index: Account<'info, HandleIndex>,
+
+    /// Only needed when the attestation comes from the configured oracle
+    #[account(seeds = [ATTESTATION_CONFIG_SEED], bump = attestation_config.bump)]
+    pub attestation_config: Option<Account<'info, AttestationConfig>>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Address is checked against the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.pending_owner == Some(new_owner.key()) @ HelmError::NoPendingOwnershipTransfer
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    #[account(
+        mut,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), twitter_account.owner.as_ref()],
+        bump = previous_owner_member.bump,
+        constraint = previous_owner_member.is_owner(twitter_account.member_epoch) @ HelmError::Unauthorized
+    )]
+    pub previous_owner_member: Account<'info, Member>,
+
+    #[account(
+        init_if_needed,
+        payer = new_owner,
+        space = MEMBER_SIZE,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_owner_member: Account<'info, Member>,
+
+    /// CHECK: Refunded the previous owner's membership rent if it is left without any role
+    #[account(mut, address = previous_owner_member.payer @ HelmError::InvalidMemberPayer)]
+    pub previous_owner_member_payer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    // Remaining accounts: open content the previous owner approved, to strip those approvals
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(new_handle: String)]
+pub struct UpdateTwitterHandle<'info> {
+    #[account(
+        mut,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner == owner.key() @ HelmError::Unauthorized,
+        // Handles are case-insensitive, so a case-only change keeps the same index
+        constraint = twitter_account.twitter_handle.to_lowercase() != new_handle.to_lowercase() @ HelmError::InvalidTwitterHandle
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    /// CHECK: Closed only if it still points at this account; it may never have been
+    /// created, or another verified account may have taken the handle over since
+    #[account(
+        mut,
+        seeds = [HANDLE_INDEX_SEED, twitter_account.twitter_handle.to_lowercase().as_bytes()],
+        bump
+    )]
+    pub old_handle_index: UncheckedAccount<'info>,
+
+    /// Taken over if an account that no longer uses the handle still holds it
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = HANDLE_INDEX_SIZE,
+        seeds = [HANDLE_INDEX_SEED, new_handle.to_lowercase().as_bytes()],
+        bump
+    )]
+    pub new_handle_index: Account<'info, HandleIndex>,
+
+    /// Only needed when the attestation comes from the configured oracle
+    #[account(seeds = [ATTESTATION_CONFIG_SEED], bump = attestation_config.bump)]
+    pub attestation_config: Option<Account<'info, AttestationConfig>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Address is checked against the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TwitterAccountValidator for UpdateTwitterHandle<'info> {}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DeregisterTwitterAccount<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [TWITTER_ACCOUNT_SEED, twitter_account.twitter_id.as_bytes()],
+        bump = twitter_account.bump,
+        constraint = twitter_account.owner == owner.key() @ HelmError::Unauthorized,
+        constraint = twitter_account.open_content_count == 0 @ HelmError::OpenContentExists
+    )]
+    pub twitter_account: Account<'info, TwitterAccount>,
+
+    #[account(
+        mut,
+        close = owner_member_payer,
+        seeds = [MEMBER_SEED, twitter_account.key().as_ref(), owner.key().as_ref()],
+        bump = owner_member.bump
+    )]
+    pub owner_member: Account<'info, Member>,
+
+    /// CHECK: Refunded the owner's membership rent; usually the owner itself
+    #[account(mut, address = owner_member.payer @ HelmError::InvalidMemberPayer)]
+    pub owner_member_payer: UncheckedAccount<'info>,
+
+    /// CHECK: Closed as well if it still points at this account, freeing the handle
+    #[account(
+        mut,
+        seeds = [HANDLE_INDEX_SEED, twitter_account.twitter_handle.to_lowercase().as_bytes()],
+        bump
+    )]
+    pub handle_index: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // Remaining accounts: every pending Invite of this account, followed by the wallet that
+    // created it, and every other Member PDA, followed by the wallet that paid its rent;
+    // content and its revisions must already be closed
 }
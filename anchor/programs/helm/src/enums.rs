@@ -1,9 +1,93 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants::{
+        MAX_MEDIA_ITEMS,
+        MAX_POLL_DURATION_MINUTES,
+        MAX_POLL_OPTIONS,
+        MAX_THREAD_LENGTH,
+        MIN_POLL_DURATION_MINUTES,
+        MIN_POLL_OPTIONS,
+    },
+    errors::HelmError,
+};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
 pub enum ContentType {
     Tweet,
-    Thread { tweet_count: u8 },
+    /// `tweets_root` is the merkle root over the keccak256 hash of each tweet, in order
+    Thread { tweet_count: u8, tweets_root: [u8; 32] },
+    /// Reply to an existing tweet
+    Reply { in_reply_to: u64 },
+    /// Quote tweet of an existing tweet
+    Quote { quoted_tweet: u64 },
+    /// Poll whose options are committed to by their keccak256 hashes
+    Poll { option_hashes: Vec<[u8; 32]>, duration_minutes: u16 },
+    /// Tweet with attached media, committed to by their keccak256 hashes
+    Media { media_hashes: Vec<[u8; 32]> },
+}
+
+impl ContentType {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ContentType::Tweet => {}
+            ContentType::Thread { tweet_count, tweets_root } => {
+                require!(*tweet_count <= MAX_THREAD_LENGTH, HelmError::ThreadTooLong);
+                require!(*tweet_count >= 2, HelmError::InvalidThread);
+                require!(*tweets_root != [0u8; 32], HelmError::InvalidThread);
+            }
+            ContentType::Reply { in_reply_to: target } |
+            ContentType::Quote { quoted_tweet: target } => {
+                require!(*target > 0, HelmError::InvalidTargetTweet);
+            }
+            ContentType::Poll { option_hashes, duration_minutes } => {
+                require!(
+                    (MIN_POLL_OPTIONS..=MAX_POLL_OPTIONS).contains(&option_hashes.len()),
+                    HelmError::InvalidPoll
+                );
+                require!(Self::are_distinct_hashes(option_hashes), HelmError::InvalidPoll);
+                require!(
+                    (MIN_POLL_DURATION_MINUTES..=MAX_POLL_DURATION_MINUTES).contains(duration_minutes),
+                    HelmError::InvalidPoll
+                );
+            }
+            ContentType::Media { media_hashes } => {
+                require!(
+                    !media_hashes.is_empty() && media_hashes.len() <= MAX_MEDIA_ITEMS,
+                    HelmError::InvalidMedia
+                );
+                require!(Self::are_distinct_hashes(media_hashes), HelmError::InvalidMedia);
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of tweets the service posts for this content
+    pub fn tweet_count(&self) -> usize {
+        match self {
+            ContentType::Thread { tweet_count, .. } => *tweet_count as usize,
+            _ => 1,
+        }
+    }
+
+    // Hashes must be set and unique
+    fn are_distinct_hashes(hashes: &[[u8; 32]]) -> bool {
+        hashes
+            .iter()
+            .enumerate()
+            .all(|(i, hash)| *hash != [0u8; 32] && !hashes[..i].contains(hash))
+    }
+}
+
+/// What happens to existing approvals when content is rescheduled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy)]
+pub enum ReschedulePolicy {
+    /// Approvals carry over to the new time
+    KeepApprovals,
+    /// Approvals are kept, but another admin has to confirm the new time
+    RequireReapproval,
+    /// Approvals are dropped and the content goes back to review
+    ResetApprovals,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Copy)]
@@ -15,4 +99,6 @@ pub enum ContentStatus {
     Published,
     Failed,
     Canceled,
+    /// Schedule passed without the content being published
+    Expired,
 }
@@ -13,26 +13,60 @@ pub enum HelmError {
     InvalidTwitterHandle,
     #[msg("Invalid Twitter ID format")]
     InvalidTwitterId,
+    #[msg("Missing service attestation")]
+    MissingAttestation,
+    #[msg("Invalid service attestation")]
+    InvalidAttestation,
+    #[msg("Service attestation expired")]
+    AttestationExpired,
+    #[msg("Twitter account still has open content")]
+    OpenContentExists,
+    #[msg("Members, invites, content or revisions still belong to this Twitter account")]
+    AccountRecordsRemain,
+
+    // Ownership Transfer Errors
+    #[msg("Invalid ownership proposal")]
+    InvalidOwnershipProposal,
+    #[msg("No pending ownership transfer for this wallet")]
+    NoPendingOwnershipTransfer,
+    #[msg("Ownership proposal expired")]
+    OwnershipProposalExpired,
 
     // Content Status Errors
     #[msg("Invalid content status for operation")]
     InvalidContentStatus,
     #[msg("Content is in terminal state")]
     ContentInTerminalState,
+    #[msg("Content must be published, canceled or expired before it can be closed")]
+    ContentNotTerminal,
     #[msg("Content not active")]
     ContentNotActive,
     #[msg("Invalid state transition")]
     InvalidStateTransition,
     #[msg("Content already submitted for approval")]
     AlreadySubmitted,
+    #[msg("Content sequence overflow")]
+    ContentSequenceOverflow,
+    #[msg("Maximum number of revisions reached")]
+    MaxRevisionsReached,
 
     // Approval Errors
     #[msg("Content already approved by this admin")]
     AlreadyApproved,
+    #[msg("Content not approved by this admin")]
+    ApprovalNotFound,
     #[msg("Insufficient approvals")]
     InsufficientApprovals,
     #[msg("Invalid minimum required approvals")]
     InvalidRequiredApprovals,
+    #[msg("Required approvals exceed the number of admins")]
+    RequiredApprovalsUnreachable,
+    #[msg("Required approvals exceed what content can hold")]
+    TooManyRequiredApprovals,
+    #[msg("Invalid approval policy")]
+    InvalidApprovalPolicy,
+    #[msg("Rescheduled content must be re-approved by another admin")]
+    ReapprovalByRescheduler,
 
     // Admin Management Errors
     #[msg("Admin already exists")]
@@ -52,6 +86,36 @@ pub enum HelmError {
     #[msg("Maximum number of creators reached")]
     MaxCreatorsReached,
 
+    // Member Role Errors
+    #[msg("Invalid member role")]
+    InvalidRole,
+    #[msg("Member already has this role")]
+    RoleAlreadyGranted,
+    #[msg("Member does not have this role")]
+    RoleNotGranted,
+    #[msg("Invalid membership expiry")]
+    InvalidMembershipExpiry,
+    #[msg("Member has not expired")]
+    MemberNotExpired,
+    #[msg("Wallet is already a member")]
+    WalletAlreadyMember,
+    #[msg("The owner's key changes through an ownership transfer")]
+    CannotRotateOwnerKey,
+    #[msg("Too many admins with an expiring membership")]
+    TooManyExpiringAdmins,
+    #[msg("A member record's rent must go back to whoever paid for it")]
+    InvalidMemberPayer,
+
+    // Invite Errors
+    #[msg("Invite has expired")]
+    InviteExpired,
+    #[msg("Invalid invite expiry")]
+    InvalidInviteExpiry,
+    #[msg("Invalid invite code")]
+    InvalidInviteCode,
+    #[msg("An invite's rent must go back to whoever created it")]
+    InvalidInvitePayer,
+
     // Schedule Errors
     #[msg("Invalid scheduling time")]
     InvalidScheduleTime,
@@ -59,6 +123,10 @@ pub enum HelmError {
     ScheduleTimeRequired,
     #[msg("Schedule time in past")]
     ScheduleTimeInPast,
+    #[msg("Scheduled time not reached yet")]
+    ScheduleTimeNotReached,
+    #[msg("Content has not passed its schedule and grace period")]
+    ContentNotExpired,
 
     // Authorization Errors
     #[msg("Not authorized")]
@@ -73,6 +141,38 @@ pub enum HelmError {
     ContentTooLong,
     #[msg("Thread too long")]
     ThreadTooLong,
+    #[msg("Thread needs at least two tweets and a tweets root")]
+    InvalidThread,
+    #[msg("Tweet is not part of the approved thread")]
+    InvalidThreadProof,
+    #[msg("Operation not supported for this content type")]
+    InvalidContentType,
+    #[msg("Replies and quotes need a target tweet ID")]
+    InvalidTargetTweet,
+    #[msg("Invalid poll options or duration")]
+    InvalidPoll,
+    #[msg("Invalid media attachments")]
+    InvalidMedia,
+    #[msg("Published tweet IDs do not match content type")]
+    InvalidPublishedTweetIds,
+    #[msg("Reason too long")]
+    ReasonTooLong,
+
+    // Publication Retry Errors
+    #[msg("Maximum publish attempts reached")]
+    MaxPublishAttemptsReached,
+    #[msg("Retry not ready yet")]
+    RetryNotReady,
+    #[msg("Invalid retry time")]
+    InvalidRetryTime,
+    #[msg("Invalid maximum publish attempts")]
+    InvalidMaxPublishAttempts,
+    #[msg("Thread tweets must be published in order")]
+    ThreadItemOutOfOrder,
+    #[msg("Invalid expiry crank fee")]
+    InvalidExpiryCrankFee,
+    #[msg("Content approvals are no longer in force; requeue it for review")]
+    ApprovalsNoLongerInForce,
 
     // Rate Limiting
     #[msg("Too many requests")]
@@ -0,0 +1,387 @@
+// File: src/events.rs
+use anchor_lang::prelude::*;
+
+use crate::{ enums::{ ContentStatus, ContentType, ReschedulePolicy }, state::ApprovalPolicy };
+
+// Twitter account events
+
+#[event]
+pub struct TwitterAccountRegistered {
+    pub twitter_account: Pubkey,
+    pub twitter_id: String,
+    pub twitter_handle: String,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TwitterAccountVerified {
+    pub twitter_account: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TwitterAccountReclaimed {
+    pub twitter_account: Pubkey,
+    pub twitter_id: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TwitterAccountDeregistered {
+    pub twitter_account: Pubkey,
+    pub twitter_id: String,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipProposed {
+    pub twitter_account: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub twitter_account: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HandleUpdated {
+    pub twitter_account: Pubkey,
+    pub previous_handle: String,
+    pub new_handle: String,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApprovalPolicyUpdated {
+    pub twitter_account: Pubkey,
+    pub approval_policy: ApprovalPolicy,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxPublishAttemptsUpdated {
+    pub twitter_account: Pubkey,
+    pub max_publish_attempts: u8,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReschedulePolicyUpdated {
+    pub twitter_account: Pubkey,
+    pub reschedule_policy: ReschedulePolicy,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExpiryCrankFeeUpdated {
+    pub twitter_account: Pubkey,
+    pub expiry_crank_fee: u64,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationOracleUpdated {
+    pub oracle: Option<Pubkey>,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+// Membership events
+
+#[event]
+pub struct AdminAdded {
+    pub twitter_account: Pubkey,
+    pub admin: Pubkey,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminRemoved {
+    pub twitter_account: Pubkey,
+    pub admin: Pubkey,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorAdded {
+    pub twitter_account: Pubkey,
+    pub creator: Pubkey,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorRemoved {
+    pub twitter_account: Pubkey,
+    pub creator: Pubkey,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted for every role change, alongside the admin and creator events above
+#[event]
+pub struct MemberRolesUpdated {
+    pub twitter_account: Pubkey,
+    pub wallet: Pubkey,
+    pub previous_roles: u8,
+    pub roles: u8,
+    pub expires_at: Option<i64>,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminKeyRotated {
+    pub twitter_account: Pubkey,
+    pub old_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+    pub roles: u8,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InviteCreated {
+    pub invite: Pubkey,
+    pub twitter_account: Pubkey,
+    pub invitee: Pubkey,
+    pub roles: u8,
+    pub has_code: bool,
+    pub expires_at: i64,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InviteAccepted {
+    pub invite: Pubkey,
+    pub twitter_account: Pubkey,
+    pub invitee: Pubkey,
+    pub roles: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InviteRevoked {
+    pub invite: Pubkey,
+    pub twitter_account: Pubkey,
+    pub invitee: Pubkey,
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+// Content events; status changes carry both the old and the new status
+
+#[event]
+pub struct ContentCreated {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub sequence: u64,
+    pub actor: Pubkey,
+    pub content_type: ContentType,
+    pub content_hash: [u8; 32],
+    pub scheduled_for: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DraftUpdated {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub content_type: ContentType,
+    pub content_hash: [u8; 32],
+    pub scheduled_for: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentSubmitted {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentApproved {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub approval_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApprovalRevoked {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub approval_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentRejected {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentRevised {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub revision: u16,
+    pub content_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentRevisionClosed {
+    pub content_revision: Pubkey,
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub revision: u16,
+    pub revised_by: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentRescheduled {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub previous_schedule: Option<i64>,
+    pub scheduled_for: i64,
+    pub policy: ReschedulePolicy,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentCanceled {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentPublished {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub tweet_ids: Vec<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ThreadItemPublished {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub index: u8,
+    pub tweet_id: u64,
+    pub remaining: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentFailed {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub reason: String,
+    pub publish_attempts: u8,
+    pub next_retry_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentRetried {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub timestamp: i64,
+}
+
+/// Approved content sent back into review because its approvals no longer hold
+#[event]
+pub struct ContentRequeued {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentExpired {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub actor: Pubkey,
+    pub old_status: ContentStatus,
+    pub new_status: ContentStatus,
+    pub scheduled_for: Option<i64>,
+    pub crank_fee: u64,
+    pub timestamp: i64,
+}
+
+/// Receipt left behind when a finished content account is closed
+#[event]
+pub struct ContentClosed {
+    pub content: Pubkey,
+    pub twitter_account: Pubkey,
+    pub sequence: u64,
+    pub author: Pubkey,
+    pub content_hash: [u8; 32],
+    pub status: ContentStatus,
+    pub published_tweet_ids: Vec<u64>,
+    pub published_at: Option<i64>,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
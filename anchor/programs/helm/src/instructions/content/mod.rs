@@ -2,15 +2,173 @@
 
 use anchor_lang::prelude::*;
 use crate::{
-    contexts::content::{ SubmitContentAction, ContentAction },
-    enums::ContentStatus,
+    contexts::content::{
+        SubmitContentAction,
+        DraftAction,
+        ReviseContent,
+        ContentAction,
+        ServiceContentAction,
+        RetryContentAction,
+        CloseContent,
+        CloseContentRevision,
+        ExpireContent,
+    },
+    constants::{ MAX_CONTENT_APPROVALS, SERVICE_AUTHORITY },
+    enums::{ ContentStatus, ContentType },
     errors::HelmError,
+    events::{
+        ApprovalRevoked,
+        ContentApproved,
+        ContentCanceled,
+        ContentClosed,
+        ContentCreated,
+        ContentExpired,
+        ContentFailed,
+        ContentPublished,
+        ContentRejected,
+        ContentRequeued,
+        ContentRescheduled,
+        ContentRetried,
+        ContentRevised,
+        ContentRevisionClosed,
+        ContentSubmitted,
+        DraftUpdated,
+        ThreadItemPublished,
+    },
+    state::{ Content, Member, TwitterAccount },
 };
 
-pub fn submit_for_approval(ctx: Context<SubmitContentAction>) -> Result<()> {
+fn initialize_draft(
+    ctx: &mut Context<SubmitContentAction>,
+    content_type: ContentType,
+    content_hash: [u8; 32],
+    scheduled_for: Option<i64>
+) -> Result<()> {
+    content_type.validate()?;
+    let sequence = ctx.accounts.twitter_account.next_content_sequence()?;
+    let author = ctx.accounts.authority.key();
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // Initialize content with provided parameters
+    ctx.accounts.content.initialize(
+        ctx.accounts.twitter_account.key(),
+        sequence,
+        author,
+        author,
+        content_type.clone(),
+        content_hash,
+        scheduled_for,
+        ctx.bumps.content,
+        timestamp
+    );
+
+    emit_cpi!(ContentCreated {
+        content: ctx.accounts.content.key(),
+        twitter_account: ctx.accounts.twitter_account.key(),
+        sequence,
+        actor: author,
+        content_type,
+        content_hash,
+        scheduled_for,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn create_draft(
+    mut ctx: Context<SubmitContentAction>,
+    content_type: ContentType,
+    content_hash: [u8; 32],
+    scheduled_for: Option<i64>
+) -> Result<()> {
+    initialize_draft(&mut ctx, content_type, content_hash, scheduled_for)
+}
+
+pub fn update_draft(
+    ctx: Context<DraftAction>,
+    content_type: ContentType,
+    content_hash: [u8; 32],
+    scheduled_for: Option<i64>
+) -> Result<()> {
     let content = &mut ctx.accounts.content;
     let clock = Clock::get()?;
 
+    content.update_draft(content_type.clone(), content_hash, scheduled_for, clock.unix_timestamp)?;
+
+    emit_cpi!(DraftUpdated {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        content_type,
+        content_hash,
+        scheduled_for,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn submit_draft(ctx: Context<DraftAction>) -> Result<()> {
+    let submitter = ctx.accounts.authority.key();
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+
+    let twitter_account = &ctx.accounts.twitter_account;
+    let submitter_is_admin = ctx.accounts.member.is_admin(twitter_account.member_epoch);
+
+    let timestamp = submit(content, submitter, submitter_is_admin, twitter_account)?;
+
+    emit_cpi!(ContentSubmitted {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: submitter,
+        old_status,
+        new_status: content.status,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn submit_for_approval(
+    mut ctx: Context<SubmitContentAction>,
+    content_type: ContentType,
+    content_hash: [u8; 32],
+    scheduled_for: Option<i64>
+) -> Result<()> {
+    initialize_draft(&mut ctx, content_type, content_hash, scheduled_for)?;
+
+    let submitter = ctx.accounts.authority.key();
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+
+    let twitter_account = &ctx.accounts.twitter_account;
+    let submitter_is_admin = ctx.accounts.member.is_admin(twitter_account.member_epoch);
+
+    let timestamp = submit(content, submitter, submitter_is_admin, twitter_account)?;
+
+    emit_cpi!(ContentSubmitted {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: submitter,
+        old_status,
+        new_status: content.status,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+/// Moves content into review and returns the submission time
+fn submit(
+    content: &mut Content,
+    submitter: Pubkey,
+    submitter_is_admin: bool,
+    twitter_account: &TwitterAccount
+) -> Result<i64> {
+    let clock = Clock::get()?;
+
     // Validate schedule time if present
     if let Some(scheduled_time) = content.scheduled_for {
         require!(scheduled_time > clock.unix_timestamp, HelmError::ScheduleTimeInPast);
@@ -19,72 +177,505 @@ pub fn submit_for_approval(ctx: Context<SubmitContentAction>) -> Result<()> {
 
     // Transition to pending approval
     content.transition_to(ContentStatus::PendingApproval)?;
+    content.sync_approvals_epoch(twitter_account.member_epoch);
 
-    // Handle initial approval from submitter
-    let approver_key = ctx.accounts.authority.key();
-    if !content.approvals.contains(&approver_key) {
-        content.approvals.push(approver_key);
+    // Handle initial approval from submitter; creator submissions still need admin review
+    if submitter_is_admin && !content.approvals.contains(&submitter) {
+        content.approvals.push(submitter);
 
         // Check if we have enough approvals to auto-approve
-        let required_approvals = ctx.accounts.twitter_account.required_approvals as usize;
-        if content.approvals.len() >= required_approvals {
+        let policy = &twitter_account.approval_policy;
+        let admin_count = twitter_account.active_admin_count(clock.unix_timestamp);
+        if policy.is_satisfied(&content.approvals, admin_count) {
             content.transition_to(ContentStatus::Approved)?;
         }
     }
 
+    Ok(clock.unix_timestamp)
+}
+
+pub fn revise_content(ctx: Context<ReviseContent>, content_hash: [u8; 32]) -> Result<()> {
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
+
+    // Archive the rejected revision before it is replaced
+    ctx.accounts.twitter_account.revision_record_created();
+    ctx.accounts.content_revision.initialize(
+        content.key(),
+        content,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+        ctx.bumps.content_revision
+    );
+
+    content.revise(content_hash, clock.unix_timestamp)?;
+
+    emit_cpi!(ContentRevised {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        old_status,
+        new_status: content.status,
+        revision: content.revision,
+        content_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
-pub fn approve_content(ctx: Context<ContentAction>) -> Result<()> {
+/// Approvals still backed by an active admin membership. Only approvers whose Member PDA
+/// is passed in `members` count, so approvals from admins who were removed or whose
+/// membership lapsed never do. Closed records are skipped, so callers may pass the PDA
+/// of every approver.
+pub fn approvals_in_force<'info>(
+    content: &Content,
+    twitter_account: &Account<'info, TwitterAccount>,
+    members: &'info [AccountInfo<'info>]
+) -> Result<Vec<Pubkey>> {
+    let mut in_force = Vec::new();
+    if content.approvals_epoch != twitter_account.member_epoch {
+        return Ok(in_force);
+    }
+
+    for info in members.iter().filter(|info| info.owner == &crate::ID) {
+        let member = Account::<Member>::try_from(info)?;
+        require_keys_eq!(
+            member.twitter_account,
+            twitter_account.key(),
+            HelmError::InvalidTwitterAccount
+        );
+        if
+            member.is_admin(twitter_account.member_epoch) &&
+            content.approvals.contains(&member.wallet) &&
+            !in_force.contains(&member.wallet)
+        {
+            in_force.push(member.wallet);
+        }
+    }
+
+    Ok(in_force)
+}
+
+pub fn approve_content<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ContentAction<'info>>
+) -> Result<()> {
     let content = &mut ctx.accounts.content;
     let twitter_account = &ctx.accounts.twitter_account;
     let approver_key = ctx.accounts.authority.key();
+    let old_status = content.status;
     let clock = Clock::get()?;
 
     // Validate current state
     require!(content.status == ContentStatus::PendingApproval, HelmError::InvalidContentStatus);
-    require!(!content.approvals.contains(&approver_key), HelmError::AlreadyApproved);
+    content.sync_approvals_epoch(twitter_account.member_epoch);
+
+    // A reschedule awaiting confirmation lets earlier approvers confirm the new time
+    match content.rescheduled_by {
+        Some(rescheduled_by) => {
+            require!(approver_key != rescheduled_by, HelmError::ReapprovalByRescheduler);
+        }
+        None => {
+            require!(!content.approvals.contains(&approver_key), HelmError::AlreadyApproved);
+        }
+    }
 
     // Check schedule time if present
     if let Some(scheduled_time) = content.scheduled_for {
         require!(scheduled_time > clock.unix_timestamp, HelmError::ScheduleTimeInPast);
     }
 
-    // Get required approvals
-    let required_approvals = twitter_account.required_approvals as usize;
-
-    // Verify we won't exceed max approvals
-    require!(content.approvals.len() < required_approvals * 2, HelmError::InvalidContentStatus);
-
     // Add the approval
-    content.approvals.push(approver_key);
+    if !content.approvals.contains(&approver_key) {
+        // Verify we won't exceed max approvals
+        require!(content.approvals.len() < MAX_CONTENT_APPROVALS, HelmError::InvalidContentStatus);
+        content.approvals.push(approver_key);
+    }
+    content.rescheduled_by = None;
 
-    // Check if we've hit the required approvals
-    if content.approvals.len() >= required_approvals {
+    // Check if the account's approval policy is now satisfied; earlier approvers' Member
+    // PDAs are passed as remaining accounts, the approver's own is checked by the context
+    let mut in_force = approvals_in_force(content, twitter_account, ctx.remaining_accounts)?;
+    if !in_force.contains(&approver_key) {
+        in_force.push(approver_key);
+    }
+    let admin_count = twitter_account.active_admin_count(clock.unix_timestamp);
+    if twitter_account.approval_policy.is_satisfied(&in_force, admin_count) {
         content.transition_to(ContentStatus::Approved)?;
     }
 
     content.updated_at = clock.unix_timestamp;
+
+    emit_cpi!(ContentApproved {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: approver_key,
+        old_status,
+        new_status: content.status,
+        approval_count: content.approvals.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn revoke_approval(ctx: Context<ContentAction>) -> Result<()> {
+    let approver_key = ctx.accounts.authority.key();
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+
+    content.sync_approvals_epoch(ctx.accounts.twitter_account.member_epoch);
+    content.revoke_approval(
+        &approver_key,
+        &ctx.accounts.twitter_account.approval_policy,
+        ctx.accounts.twitter_account.active_admin_count(Clock::get()?.unix_timestamp)
+    )?;
+
+    emit_cpi!(ApprovalRevoked {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: approver_key,
+        old_status,
+        new_status: content.status,
+        approval_count: content.approvals.len() as u8,
+        timestamp: content.updated_at,
+    });
+
+    Ok(())
+}
+
+pub fn reschedule_content(ctx: Context<ContentAction>, scheduled_for: i64) -> Result<()> {
+    let policy = ctx.accounts.twitter_account.reschedule_policy;
+    let actor = ctx.accounts.authority.key();
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let previous_schedule = content.scheduled_for;
+    let clock = Clock::get()?;
+
+    content.reschedule(scheduled_for, policy, actor, &clock)?;
+
+    emit_cpi!(ContentRescheduled {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor,
+        old_status,
+        new_status: content.status,
+        previous_schedule,
+        scheduled_for,
+        policy,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
 pub fn reject_content(ctx: Context<ContentAction>, reason: String) -> Result<()> {
     let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
 
-    require!(content.status == ContentStatus::PendingApproval, HelmError::InvalidContentStatus);
-    content.transition_to(ContentStatus::Rejected)?;
-    content.rejection_reason = Some(reason);
-    content.updated_at = Clock::get()?.unix_timestamp;
+    content.reject(reason.clone())?;
+
+    emit_cpi!(ContentRejected {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        old_status,
+        new_status: content.status,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
 
 pub fn cancel_content(ctx: Context<ContentAction>) -> Result<()> {
     let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
 
     require!(!content.is_terminal(), HelmError::ContentInTerminalState);
+    // Half a thread is already out; the service has to finish posting it
+    require!(content.published_items == 0, HelmError::InvalidContentStatus);
     content.transition_to(ContentStatus::Canceled)?;
-    content.updated_at = Clock::get()?.unix_timestamp;
+    content.updated_at = clock.unix_timestamp;
+    ctx.accounts.twitter_account.close_content();
+
+    emit_cpi!(ContentCanceled {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        old_status,
+        new_status: content.status,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Whether approved content's approvals still meet the policy. Approvers' Member PDAs
+/// are passed as remaining accounts.
+fn approvals_hold<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, ServiceContentAction<'info>>
+) -> Result<bool> {
+    let twitter_account = &ctx.accounts.twitter_account;
+    let content = &ctx.accounts.content;
+    // A thread's approvals were checked before its first tweet went out
+    if content.status != ContentStatus::Approved || content.published_items != 0 {
+        return Ok(true);
+    }
+
+    let in_force = approvals_in_force(content, twitter_account, ctx.remaining_accounts)?;
+    let admin_count = twitter_account.active_admin_count(Clock::get()?.unix_timestamp);
+    Ok(twitter_account.approval_policy.is_satisfied(&in_force, admin_count))
+}
+
+/// Sends approved content whose approvals no longer hold back into review, once the
+/// service has been refused publication. Approvers' Member PDAs are passed as
+/// remaining accounts.
+pub fn requeue_content<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ServiceContentAction<'info>>
+) -> Result<()> {
+    let status = ctx.accounts.content.status;
+    require!(status == ContentStatus::Approved, HelmError::InvalidContentStatus);
+    // Only content the service would be refused is sent back
+    require!(!approvals_hold(&ctx)?, HelmError::InvalidContentStatus);
+
+    // Approvals from before a reclaim, or from admins who have since left, no longer count
+    let twitter_account = &ctx.accounts.twitter_account;
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    content.sync_approvals_epoch(twitter_account.member_epoch);
+    content.transition_to(ContentStatus::PendingApproval)?;
+
+    emit_cpi!(ContentRequeued {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        old_status,
+        new_status: content.status,
+        timestamp: content.updated_at,
+    });
+
+    Ok(())
+}
+
+pub fn mark_published<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ServiceContentAction<'info>>,
+    tweet_ids: Vec<u64>
+) -> Result<()> {
+    require!(approvals_hold(&ctx)?, HelmError::ApprovalsNoLongerInForce);
+
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
+
+    content.publish(tweet_ids, clock.unix_timestamp)?;
+    ctx.accounts.twitter_account.close_content();
+
+    emit_cpi!(ContentPublished {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        old_status,
+        new_status: content.status,
+        tweet_ids: content.published_tweet_ids.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn mark_thread_item_published<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ServiceContentAction<'info>>,
+    index: u8,
+    tweet_hash: [u8; 32],
+    proof: Vec<[u8; 32]>,
+    tweet_id: u64
+) -> Result<()> {
+    require!(approvals_hold(&ctx)?, HelmError::ApprovalsNoLongerInForce);
+
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
+
+    let completed = content.publish_thread_item(
+        index,
+        &tweet_hash,
+        &proof,
+        tweet_id,
+        clock.unix_timestamp
+    )?;
+
+    let tweet_count = content.content_type.tweet_count();
+    let published_count = content.published_items.count_ones() as usize;
+    emit_cpi!(ThreadItemPublished {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        index,
+        tweet_id,
+        remaining: (tweet_count - published_count) as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if completed {
+        ctx.accounts.twitter_account.close_content();
+
+        emit_cpi!(ContentPublished {
+            content: content.key(),
+            twitter_account: content.twitter_account,
+            actor: ctx.accounts.authority.key(),
+            old_status,
+            new_status: content.status,
+            tweet_ids: content.published_tweet_ids.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     Ok(())
 }
+
+pub fn mark_failed(
+    ctx: Context<ServiceContentAction>,
+    reason: String,
+    next_retry_at: Option<i64>
+) -> Result<()> {
+    let max_attempts = ctx.accounts.twitter_account.max_publish_attempts;
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
+
+    content.fail(reason.clone(), next_retry_at, max_attempts, clock.unix_timestamp)?;
+
+    emit_cpi!(ContentFailed {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        old_status,
+        new_status: content.status,
+        reason,
+        publish_attempts: content.publish_attempts,
+        next_retry_at: content.next_retry_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn retry_content(ctx: Context<RetryContentAction>, requeue_for_approval: bool) -> Result<()> {
+    // Only admins may send content back through review
+    if requeue_for_approval {
+        require!(ctx.accounts.authority.key() != SERVICE_AUTHORITY, HelmError::Unauthorized);
+    }
+
+    let max_attempts = ctx.accounts.twitter_account.max_publish_attempts;
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
+
+    content.retry(requeue_for_approval, max_attempts, clock.unix_timestamp)?;
+
+    emit_cpi!(ContentRetried {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: ctx.accounts.authority.key(),
+        old_status,
+        new_status: content.status,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn close_content(ctx: Context<CloseContent>) -> Result<()> {
+    ctx.accounts.twitter_account.content_record_closed();
+    let content = &ctx.accounts.content;
+
+    // The account itself is closed to the payer by the context; keep a receipt in the logs
+    emit_cpi!(ContentClosed {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        sequence: content.sequence,
+        author: content.author,
+        content_hash: content.content_hash,
+        status: content.status,
+        published_tweet_ids: content.published_tweet_ids.clone(),
+        published_at: content.published_at,
+        closed_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn close_content_revision(ctx: Context<CloseContentRevision>) -> Result<()> {
+    // Revisions of content still in review remain part of its history
+    let content_info = &ctx.accounts.content;
+    if content_info.owner == &crate::ID && !content_info.data_is_empty() {
+        let content = Content::try_deserialize(&mut &content_info.try_borrow_data()?[..])?;
+        require!(content.is_terminal(), HelmError::ContentNotTerminal);
+    }
+
+    ctx.accounts.twitter_account.revision_record_closed();
+
+    // The revision itself is closed to its author by the context
+    let content_revision = &ctx.accounts.content_revision;
+    emit_cpi!(ContentRevisionClosed {
+        content_revision: content_revision.key(),
+        content: content_revision.content,
+        twitter_account: content_revision.twitter_account,
+        revision: content_revision.revision,
+        revised_by: content_revision.revised_by,
+        closed_by: ctx.accounts.caller.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn expire_content(ctx: Context<ExpireContent>) -> Result<()> {
+    let crank_fee = ctx.accounts.twitter_account.expiry_crank_fee;
+    let caller = ctx.accounts.caller.key();
+    let content = &mut ctx.accounts.content;
+    let old_status = content.status;
+    let clock = Clock::get()?;
+
+    content.expire(clock.unix_timestamp)?;
+    ctx.accounts.twitter_account.close_content();
+
+    emit_cpi!(ContentExpired {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        actor: caller,
+        old_status,
+        new_status: content.status,
+        scheduled_for: content.scheduled_for,
+        crank_fee,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if crank_fee == 0 {
+        return Ok(());
+    }
+
+    // The account cannot stay open below rent exemption, so paying the fee closes it
+    emit_cpi!(ContentClosed {
+        content: content.key(),
+        twitter_account: content.twitter_account,
+        sequence: content.sequence,
+        author: content.author,
+        content_hash: content.content_hash,
+        status: content.status,
+        published_tweet_ids: content.published_tweet_ids.clone(),
+        published_at: content.published_at,
+        closed_by: caller,
+        timestamp: clock.unix_timestamp,
+    });
+
+    ctx.accounts.twitter_account.content_record_closed();
+    content.sub_lamports(crank_fee)?;
+    ctx.accounts.caller.add_lamports(crank_fee)?;
+    ctx.accounts.content.close(ctx.accounts.payer.to_account_info())
+}
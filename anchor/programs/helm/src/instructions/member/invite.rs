@@ -0,0 +1,146 @@
+// File: src/instructions/member/invite.rs
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ ASSIGNABLE_ROLES, ROLE_ADMIN, ROLE_CREATOR },
+    contexts::member::{ require_roles_absent, AcceptInvite, CreateInvite, RevokeInvite },
+    errors::HelmError,
+    events::{
+        AdminAdded,
+        CreatorAdded,
+        InviteAccepted,
+        InviteCreated,
+        InviteRevoked,
+        MemberRolesUpdated,
+    },
+};
+
+use super::retire;
+
+pub fn create_invite(
+    ctx: Context<CreateInvite>,
+    invitee: Pubkey,
+    roles: u8,
+    expires_at: i64,
+    code_hash: Option<[u8; 32]>
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(roles != 0 && roles & !ASSIGNABLE_ROLES == 0, HelmError::InvalidRole);
+    require!(expires_at > clock.unix_timestamp, HelmError::InvalidInviteExpiry);
+
+    ctx.accounts.twitter_account.invite_record_created();
+    let twitter_account = &ctx.accounts.twitter_account;
+    ctx.accounts.invite.initialize(
+        twitter_account.key(),
+        invitee,
+        roles,
+        code_hash,
+        twitter_account.member_epoch,
+        ctx.accounts.owner.key(),
+        clock.unix_timestamp,
+        expires_at,
+        ctx.bumps.invite
+    );
+
+    emit_cpi!(InviteCreated {
+        invite: ctx.accounts.invite.key(),
+        twitter_account: twitter_account.key(),
+        invitee,
+        roles,
+        has_code: code_hash.is_some(),
+        expires_at,
+        actor: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// The invitee signs to join; the invite is closed back to whoever created it
+pub fn accept_invite(ctx: Context<AcceptInvite>, code: Option<String>) -> Result<()> {
+    let clock = Clock::get()?;
+    let invite = &ctx.accounts.invite;
+    let twitter_account = &mut ctx.accounts.twitter_account;
+    let member = &mut ctx.accounts.member;
+    let epoch = twitter_account.member_epoch;
+
+    // Invites from before a reclaim lapse along with the memberships of that era
+    require!(
+        !invite.is_expired(clock.unix_timestamp) && invite.epoch == epoch,
+        HelmError::InviteExpired
+    );
+    require!(invite.matches_code(code.as_deref()), HelmError::InvalidInviteCode);
+    twitter_account.invite_record_closed();
+
+    if member.is_new() {
+        twitter_account.member_record_created();
+    }
+
+    if member.is_counted(epoch) && member.is_expired() {
+        retire(twitter_account, member)?;
+    }
+    if !member.is_active(epoch) {
+        member.initialize(
+            twitter_account.key(),
+            invite.invitee,
+            epoch,
+            invite.invited_by,
+            invite.invitee,
+            clock.unix_timestamp,
+            ctx.bumps.member
+        );
+    }
+    require_roles_absent(member.active_roles(epoch), invite.roles)?;
+
+    let previous_roles = member.roles;
+    member.roles |= invite.roles;
+
+    if invite.roles & ROLE_ADMIN != 0 {
+        twitter_account.add_admin()?;
+        twitter_account.track_admin_expiry(member.expires_at)?;
+    }
+
+    let twitter_account = twitter_account.key();
+    let wallet = invite.invitee;
+    let roles = member.roles;
+    let expires_at = member.expires_at;
+    let timestamp = clock.unix_timestamp;
+
+    if invite.roles & ROLE_ADMIN != 0 {
+        emit_cpi!(AdminAdded { twitter_account, admin: wallet, actor: wallet, timestamp });
+    }
+    if invite.roles & ROLE_CREATOR != 0 {
+        emit_cpi!(CreatorAdded { twitter_account, creator: wallet, actor: wallet, timestamp });
+    }
+    emit_cpi!(MemberRolesUpdated {
+        twitter_account,
+        wallet,
+        previous_roles,
+        roles,
+        expires_at,
+        actor: wallet,
+        timestamp,
+    });
+    emit_cpi!(InviteAccepted {
+        invite: ctx.accounts.invite.key(),
+        twitter_account,
+        invitee: wallet,
+        roles: ctx.accounts.invite.roles,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn revoke_invite(ctx: Context<RevokeInvite>) -> Result<()> {
+    ctx.accounts.twitter_account.invite_record_closed();
+
+    emit_cpi!(InviteRevoked {
+        invite: ctx.accounts.invite.key(),
+        twitter_account: ctx.accounts.twitter_account.key(),
+        invitee: ctx.accounts.invite.invitee,
+        actor: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
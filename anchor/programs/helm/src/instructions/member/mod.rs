@@ -0,0 +1,269 @@
+// File: src/instructions/member/mod.rs
+pub mod invite;
+pub mod rotate;
+
+pub use invite::*;
+pub use rotate::*;
+
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ ROLE_ADMIN, ROLE_CREATOR },
+    contexts::member::{ ManageMember, MemberValidator, PruneExpiredMembers },
+    errors::HelmError,
+    events::{ AdminAdded, AdminRemoved, CreatorAdded, CreatorRemoved, MemberRolesUpdated },
+    state::{ Content, Invite, Member, TwitterAccount },
+};
+
+/// `expires_at` applies to the whole membership, replacing any earlier expiry. An
+/// admin's expiry is tracked with their admin slot, so an existing admin keeps theirs.
+pub fn grant(
+    ctx: Context<ManageMember>,
+    wallet: Pubkey,
+    roles: u8,
+    expires_at: Option<i64>
+) -> Result<()> {
+    ctx.accounts.validate_roles(roles)?;
+    ctx.accounts.validate_roles_absent(roles)?;
+    ctx.accounts.validate_expiry(&wallet, expires_at)?;
+
+    let twitter_account = &mut ctx.accounts.twitter_account;
+    let member = &mut ctx.accounts.member;
+    let clock = Clock::get()?;
+
+    if member.is_new() {
+        twitter_account.member_record_created();
+    }
+
+    // An expired record still counts as an admin until it is retired
+    if member.is_counted(twitter_account.member_epoch) && member.is_expired() {
+        retire(twitter_account, member)?;
+    }
+
+    // New records and ones left over from before a reclaim start from scratch
+    if !member.is_active(twitter_account.member_epoch) {
+        member.initialize(
+            twitter_account.key(),
+            wallet,
+            twitter_account.member_epoch,
+            ctx.accounts.owner.key(),
+            ctx.accounts.owner.key(),
+            clock.unix_timestamp,
+            ctx.bumps.member
+        );
+    }
+
+    let previous_roles = member.roles;
+    member.roles |= roles;
+    if previous_roles & ROLE_ADMIN == 0 {
+        member.expires_at = expires_at;
+    }
+
+    if roles & ROLE_ADMIN != 0 {
+        twitter_account.add_admin()?;
+        twitter_account.track_admin_expiry(member.expires_at)?;
+    }
+
+    emit_role_changes(&ctx, wallet, previous_roles, clock.unix_timestamp)?;
+
+    Ok(())
+}
+
+pub fn revoke<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ManageMember<'info>>,
+    wallet: Pubkey,
+    roles: u8
+) -> Result<()> {
+    ctx.accounts.validate_roles(roles)?;
+    ctx.accounts.validate_roles_present(roles)?;
+
+    if roles & ROLE_ADMIN != 0 {
+        let expires_at = ctx.accounts.member.expires_at;
+        let twitter_account = &mut ctx.accounts.twitter_account;
+
+        // The owner always keeps its admin role, so at least one admin remains
+        require!(wallet != twitter_account.owner, HelmError::CannotRemoveLastAdmin);
+        require!(twitter_account.admin_count > 1, HelmError::CannotRemoveLastAdmin);
+
+        // Refuse removals that would put a reachable approval policy out of reach
+        twitter_account.remove_admin(&wallet, expires_at)?;
+
+        drop_approvals(ctx.remaining_accounts, twitter_account, &wallet)?;
+    }
+
+    let previous_roles = ctx.accounts.member.roles;
+    ctx.accounts.member.roles &= !roles;
+
+    emit_role_changes(&ctx, wallet, previous_roles, Clock::get()?.unix_timestamp)?;
+
+    // Nothing left to grant; hand the rent back to whoever paid for the record
+    if ctx.accounts.member.roles == 0 {
+        ctx.accounts.twitter_account.member_record_closed();
+        let payer = ctx.accounts.member_payer.as_ref().map(|payer| payer.to_account_info());
+        close_member(&ctx.accounts.member, payer.as_ref())?;
+    }
+
+    Ok(())
+}
+
+/// Permissionless: anyone may clear out expired memberships, passed as remaining
+/// accounts, so they stop counting toward the admin count and approval groups.
+/// Records from before a reclaim are closed as well. An admin the approval policy
+/// cannot do without is kept until the owner changes the policy.
+pub fn prune_expired<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PruneExpiredMembers<'info>>
+) -> Result<()> {
+    let twitter_account_key = ctx.accounts.twitter_account.key();
+    let epoch = ctx.accounts.twitter_account.member_epoch;
+    let caller = ctx.accounts.caller.key();
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let mut member = Account::<Member>::try_from(&accounts[0])?;
+        require_keys_eq!(
+            member.twitter_account,
+            twitter_account_key,
+            HelmError::InvalidTwitterAccount
+        );
+        require!(
+            member.epoch != epoch || member.is_expired(),
+            HelmError::MemberNotExpired
+        );
+
+        if member.is_counted(epoch) {
+            let wallet = member.wallet;
+            let expires_at = member.expires_at;
+            let previous_roles = retire(&mut ctx.accounts.twitter_account, &mut member)?;
+
+            if previous_roles & ROLE_ADMIN != 0 {
+                emit_cpi!(AdminRemoved {
+                    twitter_account: twitter_account_key,
+                    admin: wallet,
+                    actor: caller,
+                    timestamp,
+                });
+            }
+            if previous_roles & ROLE_CREATOR != 0 {
+                emit_cpi!(CreatorRemoved {
+                    twitter_account: twitter_account_key,
+                    creator: wallet,
+                    actor: caller,
+                    timestamp,
+                });
+            }
+            emit_cpi!(MemberRolesUpdated {
+                twitter_account: twitter_account_key,
+                wallet,
+                previous_roles,
+                roles: 0,
+                expires_at,
+                actor: caller,
+                timestamp,
+            });
+        }
+
+        ctx.accounts.twitter_account.member_record_closed();
+        close_member(&member, accounts.get(1))?;
+    }
+
+    Ok(())
+}
+
+/// Takes an expired member out of the admin count and approval groups and
+/// returns the roles it held. Approvals it gave stopped counting once it expired.
+/// Like a revoke, this refuses to leave a reachable approval policy out of reach.
+pub fn retire(twitter_account: &mut TwitterAccount, member: &mut Member) -> Result<u8> {
+    let previous_roles = member.roles;
+    if previous_roles & ROLE_ADMIN != 0 {
+        twitter_account.remove_admin(&member.wallet, member.expires_at)?;
+    }
+    member.roles = 0;
+    Ok(previous_roles)
+}
+
+/// Closes `member`, refunding its rent to `payer`, which must be the wallet that paid for it
+pub fn close_member<'info>(
+    member: &Account<'info, Member>,
+    payer: Option<&AccountInfo<'info>>
+) -> Result<()> {
+    let payer = payer.ok_or(HelmError::InvalidMemberPayer)?;
+    require_keys_eq!(payer.key(), member.payer, HelmError::InvalidMemberPayer);
+    member.close(payer.clone())
+}
+
+/// Closes `invite`, refunding its rent to `invited_by`, which must be the wallet that created it
+pub fn close_invite<'info>(
+    invite: &Account<'info, Invite>,
+    invited_by: Option<&AccountInfo<'info>>
+) -> Result<()> {
+    let invited_by = invited_by.ok_or(HelmError::InvalidInvitePayer)?;
+    require_keys_eq!(invited_by.key(), invite.invited_by, HelmError::InvalidInvitePayer);
+    invite.close(invited_by.clone())
+}
+
+/// Strips `admin`'s approval from the open content passed as remaining accounts,
+/// since approvals only count while the approver is still an admin
+pub fn drop_approvals<'info>(
+    contents: &'info [AccountInfo<'info>],
+    twitter_account: &Account<'info, TwitterAccount>,
+    admin: &Pubkey
+) -> Result<()> {
+    let admin_count = twitter_account.active_admin_count(Clock::get()?.unix_timestamp);
+    for info in contents {
+        let mut content = Account::<Content>::try_from(info)?;
+        require_keys_eq!(
+            content.twitter_account,
+            twitter_account.key(),
+            HelmError::InvalidTwitterAccount
+        );
+
+        // Approvals on finished content, or from before a reclaim, no longer matter
+        if content.is_terminal() || content.approvals_epoch != twitter_account.member_epoch {
+            continue;
+        }
+
+        let policy = &twitter_account.approval_policy;
+        if content.drop_approval(admin, policy, admin_count)? {
+            content.exit(&crate::ID)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn emit_role_changes(
+    ctx: &Context<ManageMember>,
+    wallet: Pubkey,
+    previous_roles: u8,
+    timestamp: i64
+) -> Result<()> {
+    let twitter_account = ctx.accounts.twitter_account.key();
+    let actor = ctx.accounts.owner.key();
+    let roles = ctx.accounts.member.roles;
+    let added = roles & !previous_roles;
+    let removed = previous_roles & !roles;
+
+    if added & ROLE_ADMIN != 0 {
+        emit_cpi!(AdminAdded { twitter_account, admin: wallet, actor, timestamp });
+    }
+    if removed & ROLE_ADMIN != 0 {
+        emit_cpi!(AdminRemoved { twitter_account, admin: wallet, actor, timestamp });
+    }
+    if added & ROLE_CREATOR != 0 {
+        emit_cpi!(CreatorAdded { twitter_account, creator: wallet, actor, timestamp });
+    }
+    if removed & ROLE_CREATOR != 0 {
+        emit_cpi!(CreatorRemoved { twitter_account, creator: wallet, actor, timestamp });
+    }
+
+    emit_cpi!(MemberRolesUpdated {
+        twitter_account,
+        wallet,
+        previous_roles,
+        roles,
+        expires_at: ctx.accounts.member.expires_at,
+        actor,
+        timestamp,
+    });
+
+    Ok(())
+}
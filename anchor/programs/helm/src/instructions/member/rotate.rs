@@ -0,0 +1,106 @@
+// File: src/instructions/member/rotate.rs
+use anchor_lang::prelude::*;
+use crate::{
+    contexts::member::RotateAdminKey,
+    errors::HelmError,
+    events::{ AdminKeyRotated, MemberRolesUpdated },
+    state::Content,
+};
+
+use super::retire;
+
+/// Moves an admin's membership to a new wallet without touching the admin count.
+/// Approvals on open content passed as remaining accounts move with it.
+pub fn rotate_admin_key<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RotateAdminKey<'info>>
+) -> Result<()> {
+    let old_wallet = ctx.accounts.old_wallet.key();
+    let new_wallet = ctx.accounts.new_wallet.key();
+    require_keys_neq!(old_wallet, new_wallet, HelmError::WalletAlreadyMember);
+
+    let twitter_account = &mut ctx.accounts.twitter_account;
+    let old_member = &ctx.accounts.old_member;
+    let new_member = &mut ctx.accounts.new_member;
+    let epoch = twitter_account.member_epoch;
+
+    if new_member.is_new() {
+        twitter_account.member_record_created();
+    }
+
+    // A lapsed record at the new key is cleared out first; a live one is refused
+    if new_member.is_counted(epoch) && new_member.is_expired() {
+        retire(twitter_account, new_member)?;
+    }
+    require!(!new_member.is_active(epoch), HelmError::WalletAlreadyMember);
+
+    new_member.initialize(
+        twitter_account.key(),
+        new_wallet,
+        epoch,
+        old_member.added_by,
+        new_wallet,
+        old_member.added_at,
+        ctx.bumps.new_member
+    );
+    new_member.roles = old_member.roles;
+    new_member.expires_at = old_member.expires_at;
+
+    twitter_account.approval_policy.replace_member(&old_wallet, new_wallet);
+
+    let admin_count = twitter_account.active_admin_count(Clock::get()?.unix_timestamp);
+    for info in ctx.remaining_accounts {
+        let mut content = Account::<Content>::try_from(info)?;
+        require_keys_eq!(
+            content.twitter_account,
+            twitter_account.key(),
+            HelmError::InvalidTwitterAccount
+        );
+
+        if content.is_terminal() || content.approvals_epoch != epoch {
+            continue;
+        }
+
+        let policy = &twitter_account.approval_policy;
+        if content.replace_admin(&old_wallet, new_wallet, policy, admin_count)? {
+            content.exit(&crate::ID)?;
+        }
+    }
+
+    let twitter_account = twitter_account.key();
+    let roles = new_member.roles;
+    let expires_at = new_member.expires_at;
+    let actor = ctx.accounts.authority.key();
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.twitter_account.member_record_closed();
+    ctx.accounts.old_member.close(ctx.accounts.old_member_payer.to_account_info())?;
+
+    emit_cpi!(MemberRolesUpdated {
+        twitter_account,
+        wallet: old_wallet,
+        previous_roles: roles,
+        roles: 0,
+        expires_at,
+        actor,
+        timestamp,
+    });
+    emit_cpi!(MemberRolesUpdated {
+        twitter_account,
+        wallet: new_wallet,
+        previous_roles: 0,
+        roles,
+        expires_at,
+        actor,
+        timestamp,
+    });
+    emit_cpi!(AdminKeyRotated {
+        twitter_account,
+        old_wallet,
+        new_wallet,
+        roles,
+        actor,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,9 @@
 // /src/instructions/mod.rs
 
 pub mod content;
-pub mod admin;
-pub mod creator;
+pub mod member;
 pub mod twitter;
+pub mod service;
 
 pub use content::*;
-pub use creator::*;
+pub use member::*;
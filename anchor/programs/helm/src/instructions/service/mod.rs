@@ -0,0 +1,19 @@
+// File: src/instructions/service/mod.rs
+use anchor_lang::prelude::*;
+use crate::{ contexts::service::SetAttestationOracle, events::AttestationOracleUpdated };
+
+pub fn set_attestation_oracle(ctx: Context<SetAttestationOracle>, oracle: Option<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.attestation_config;
+
+    config.oracle = oracle;
+    config.updated_at = Clock::get()?.unix_timestamp;
+    config.bump = ctx.bumps.attestation_config;
+
+    emit_cpi!(AttestationOracleUpdated {
+        oracle,
+        actor: ctx.accounts.authority.key(),
+        timestamp: config.updated_at,
+    });
+
+    Ok(())
+}
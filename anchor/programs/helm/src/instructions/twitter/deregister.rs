@@ -0,0 +1,65 @@
+// File: src/instructions/twitter/deregister.rs
+
+use anchor_lang::{ prelude::*, Discriminator };
+use crate::{
+    contexts::twitter::DeregisterTwitterAccount,
+    errors::HelmError,
+    events::TwitterAccountDeregistered,
+    instructions::member::{ close_invite, close_member },
+    state::{ HandleIndex, Invite, Member },
+};
+
+pub fn deregister<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeregisterTwitterAccount<'info>>
+) -> Result<()> {
+    // The context refuses while content is open and closes the account back to the owner;
+    // other members and pending invites cannot be enumerated on-chain, so the caller
+    // passes them along, each followed by the wallet that paid for it
+    let twitter_account_key = ctx.accounts.twitter_account.key();
+    let owner_member_key = ctx.accounts.owner_member.key();
+    HandleIndex::release(
+        &ctx.accounts.handle_index,
+        &twitter_account_key,
+        &ctx.accounts.owner
+    )?;
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(info) = accounts.next() {
+        require_keys_neq!(info.key(), owner_member_key, HelmError::InvalidTwitterAccount);
+
+        let is_invite = info.try_borrow_data()?.starts_with(&Invite::DISCRIMINATOR);
+        if is_invite {
+            let invite = Account::<Invite>::try_from(info)?;
+            require_keys_eq!(
+                invite.twitter_account,
+                twitter_account_key,
+                HelmError::InvalidTwitterAccount
+            );
+            ctx.accounts.twitter_account.invite_record_closed();
+            close_invite(&invite, accounts.next())?;
+        } else {
+            let member = Account::<Member>::try_from(info)?;
+            require_keys_eq!(
+                member.twitter_account,
+                twitter_account_key,
+                HelmError::InvalidTwitterAccount
+            );
+            ctx.accounts.twitter_account.member_record_closed();
+            close_member(&member, accounts.next())?;
+        }
+    }
+
+    // Anything left behind would come back to life if the Twitter ID registered again
+    require!(
+        !ctx.accounts.twitter_account.has_dependent_records(),
+        HelmError::AccountRecordsRemain
+    );
+
+    emit_cpi!(TwitterAccountDeregistered {
+        twitter_account: twitter_account_key,
+        twitter_id: ctx.accounts.twitter_account.twitter_id.clone(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// File: src/instructions/twitter/handle.rs
+
+use anchor_lang::prelude::*;
+use crate::{
+    attestation::{ handle_message, verify_attestation },
+    constants::SERVICE_AUTHORITY,
+    contexts::twitter::{ TwitterAccountValidator, UpdateTwitterHandle },
+    events::HandleUpdated,
+    state::{ AttestationConfig, HandleIndex },
+};
+
+pub fn update_handle(ctx: Context<UpdateTwitterHandle>, new_handle: String) -> Result<()> {
+    ctx.accounts.validate_handle_format(&new_handle)?;
+
+    // The service must confirm the Twitter account now uses this handle
+    let signers = AttestationConfig::trusted_signers(
+        ctx.accounts.attestation_config.as_deref(),
+        SERVICE_AUTHORITY
+    );
+    let message = handle_message(
+        &ctx.accounts.twitter_account.key(),
+        &ctx.accounts.twitter_account.twitter_id,
+        &new_handle
+    );
+    verify_attestation(&ctx.accounts.instructions, &signers, &message)?;
+
+    // Release the old index unless it was already taken over, then claim the new one
+    HandleIndex::release(
+        &ctx.accounts.old_handle_index,
+        &ctx.accounts.twitter_account.key(),
+        &ctx.accounts.owner
+    )?;
+    ctx.accounts.new_handle_index.initialize(
+        ctx.accounts.twitter_account.key(),
+        new_handle.clone(),
+        ctx.bumps.new_handle_index
+    );
+
+    let previous_handle = ctx.accounts.twitter_account.twitter_handle.clone();
+    let clock = Clock::get()?;
+    ctx.accounts.twitter_account.change_handle(new_handle.clone(), clock.unix_timestamp)?;
+
+    emit_cpi!(HandleUpdated {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        previous_handle,
+        new_handle,
+        actor: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
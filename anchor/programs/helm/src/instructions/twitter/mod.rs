@@ -1,4 +1,16 @@
 // /src/instructions/twitter/mod.rs
 pub mod register;
+pub mod settings;
+pub mod verify;
+pub mod reclaim;
+pub mod ownership;
+pub mod handle;
+pub mod deregister;
 
 pub use register::*;
+pub use settings::*;
+pub use verify::*;
+pub use reclaim::*;
+pub use ownership::*;
+pub use handle::*;
+pub use deregister::*;
@@ -0,0 +1,83 @@
+// File: src/instructions/twitter/ownership.rs
+
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ ROLE_ADMIN, ROLE_OWNER },
+    contexts::twitter::{ AcceptOwnership, UpdateTwitterAccount },
+    events::{ OwnershipProposed, OwnershipTransferred },
+    instructions::member::{ drop_approvals, retire },
+};
+
+pub fn propose_owner(ctx: Context<UpdateTwitterAccount>, new_owner: Pubkey, expires_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.twitter_account.propose_owner(new_owner, expires_at, clock.unix_timestamp)?;
+
+    emit_cpi!(OwnershipProposed {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        owner: ctx.accounts.owner.key(),
+        pending_owner: new_owner,
+        expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn accept_ownership<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptOwnership<'info>>) -> Result<()> {
+    let new_owner = ctx.accounts.new_owner.key();
+    let previous_owner = ctx.accounts.twitter_account.owner;
+    let clock = Clock::get()?;
+
+    let twitter_account = &mut ctx.accounts.twitter_account;
+    let epoch = twitter_account.member_epoch;
+    twitter_account.accept_ownership(new_owner, clock.unix_timestamp)?;
+
+    // The Owner role and the owner's admin slot move to the new owner together
+    let new_owner_member = &mut ctx.accounts.new_owner_member;
+    if new_owner_member.is_new() {
+        twitter_account.member_record_created();
+    }
+    if new_owner_member.is_counted(epoch) && new_owner_member.is_expired() {
+        retire(twitter_account, new_owner_member)?;
+    }
+    if !new_owner_member.is_active(epoch) {
+        new_owner_member.initialize(
+            twitter_account.key(),
+            new_owner,
+            epoch,
+            previous_owner,
+            new_owner,
+            clock.unix_timestamp,
+            ctx.bumps.new_owner_member
+        );
+    }
+    if new_owner_member.is_admin(epoch) {
+        // Already an admin, so the previous owner's slot is given up as on removal
+        twitter_account.remove_admin(&previous_owner, None)?;
+        twitter_account.untrack_admin_expiry(new_owner_member.expires_at);
+    } else {
+        twitter_account.approval_policy.replace_member(&previous_owner, new_owner);
+    }
+    new_owner_member.roles |= ROLE_OWNER | ROLE_ADMIN;
+    // The owner's own membership must never lapse
+    new_owner_member.expires_at = None;
+
+    let previous_owner_member = &mut ctx.accounts.previous_owner_member;
+    previous_owner_member.roles &= !(ROLE_OWNER | ROLE_ADMIN);
+    if previous_owner_member.roles == 0 {
+        twitter_account.member_record_closed();
+        previous_owner_member.close(ctx.accounts.previous_owner_member_payer.to_account_info())?;
+    }
+
+    // The previous owner is no longer an admin, so their approvals stop counting
+    drop_approvals(ctx.remaining_accounts, &ctx.accounts.twitter_account, &previous_owner)?;
+
+    emit_cpi!(OwnershipTransferred {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        previous_owner,
+        new_owner,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// File: src/instructions/twitter/reclaim.rs
+
+use anchor_lang::prelude::*;
+use crate::{
+    attestation::{ reclaim_message, verify_attestation },
+    constants::{ ROLE_ADMIN, ROLE_OWNER, SERVICE_AUTHORITY },
+    contexts::twitter::ReclaimTwitterAccount,
+    errors::HelmError,
+    events::TwitterAccountReclaimed,
+    state::AttestationConfig,
+};
+
+pub fn reclaim(ctx: Context<ReclaimTwitterAccount>, attestation_expires_at: i64) -> Result<()> {
+    let claimant = ctx.accounts.claimant.key();
+    let twitter_account_key = ctx.accounts.twitter_account.key();
+    let clock = Clock::get()?;
+    require!(attestation_expires_at > clock.unix_timestamp, HelmError::AttestationExpired);
+
+    // The service must vouch that the claimant controls the Twitter account
+    let signers = AttestationConfig::trusted_signers(
+        ctx.accounts.attestation_config.as_deref(),
+        SERVICE_AUTHORITY
+    );
+    let message = reclaim_message(
+        &twitter_account_key,
+        &ctx.accounts.twitter_account.twitter_id,
+        &claimant,
+        ctx.accounts.twitter_account.member_epoch,
+        attestation_expires_at,
+        &ctx.accounts.twitter_account.twitter_handle
+    );
+    verify_attestation(&ctx.accounts.instructions, &signers, &message)?;
+
+    let twitter_account = &mut ctx.accounts.twitter_account;
+    let previous_owner = twitter_account.owner;
+
+    // Bumping the member epoch drops every admin and creator the previous owner appointed,
+    // and voids this attestation for any later reclaim
+    twitter_account.reassign_owner(claimant);
+
+    // The attested handle now belongs to the claimant, whoever held its index before
+    ctx.accounts.handle_index.initialize(
+        twitter_account_key,
+        twitter_account.twitter_handle.clone(),
+        ctx.bumps.handle_index
+    );
+
+    let claimant_member = &mut ctx.accounts.claimant_member;
+    if claimant_member.is_new() {
+        twitter_account.member_record_created();
+    }
+    claimant_member.initialize(
+        twitter_account_key,
+        claimant,
+        twitter_account.member_epoch,
+        claimant,
+        claimant,
+        clock.unix_timestamp,
+        ctx.bumps.claimant_member
+    );
+    claimant_member.roles = ROLE_OWNER | ROLE_ADMIN;
+
+    emit_cpi!(TwitterAccountReclaimed {
+        twitter_account: twitter_account_key,
+        twitter_id: twitter_account.twitter_id.clone(),
+        previous_owner,
+        new_owner: claimant,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 // File: src/instructions/twitter/register.rs
 
 use anchor_lang::prelude::*;
-use crate::contexts::twitter::{ RegisterTwitterAccount, TwitterAccountValidator };
+use crate::{
+    constants::{ ROLE_ADMIN, ROLE_OWNER },
+    contexts::twitter::{ RegisterTwitterAccount, TwitterAccountValidator },
+    events::TwitterAccountRegistered,
+};
 
 pub fn register(
     ctx: Context<RegisterTwitterAccount>,
@@ -13,30 +17,38 @@ pub fn register(
     ctx.accounts.validate_id_format(&twitter_id)?;
 
     let twitter_account = &mut ctx.accounts.twitter_account;
-    let admin_list = &mut ctx.accounts.admin_list;
-    let creator_list = &mut ctx.accounts.creator_list;
+    let owner_member = &mut ctx.accounts.owner_member;
     let clock = Clock::get()?;
 
+    // The handle lookup is only claimed on verification, once the handle is attested
     // Initialize Twitter account
     twitter_account.initialize(
         ctx.accounts.owner.key(),
         twitter_id.clone(),
-        twitter_handle,
+        twitter_handle.clone(),
         clock.unix_timestamp,
         ctx.bumps.twitter_account
     );
 
-    admin_list.initialize(twitter_account.key(), ctx.accounts.owner.key(), ctx.bumps.admin_list)?;
-
-    // Initialize admin list
+    // The owner starts out as the account's only admin
+    owner_member.initialize(
+        twitter_account.key(),
+        ctx.accounts.owner.key(),
+        twitter_account.member_epoch,
+        ctx.accounts.owner.key(),
+        ctx.accounts.owner.key(),
+        clock.unix_timestamp,
+        ctx.bumps.owner_member
+    );
+    owner_member.roles = ROLE_OWNER | ROLE_ADMIN;
 
-    // Initialize creator list
-    creator_list.twitter_account = twitter_account.key();
-    creator_list.creators = Vec::new();
-    creator_list.authority = ctx.accounts.owner.key();
-    creator_list.bump = ctx.bumps.creator_list;
+    emit_cpi!(TwitterAccountRegistered {
+        twitter_account: twitter_account.key(),
+        twitter_id,
+        twitter_handle,
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
-
-// TODO: need to implement a name upgrade
@@ -0,0 +1,99 @@
+// File: src/instructions/twitter/settings.rs
+
+use anchor_lang::prelude::*;
+use crate::{
+    contexts::twitter::{ UpdateTwitterAccount, UpdateApprovalPolicy },
+    enums::ReschedulePolicy,
+    errors::HelmError,
+    events::{
+        ApprovalPolicyUpdated,
+        ExpiryCrankFeeUpdated,
+        MaxPublishAttemptsUpdated,
+        ReschedulePolicyUpdated,
+    },
+    state::{ ApprovalPolicy, Member },
+};
+
+pub fn update_max_publish_attempts(
+    ctx: Context<UpdateTwitterAccount>,
+    max_publish_attempts: u8
+) -> Result<()> {
+    ctx.accounts.twitter_account.update_max_publish_attempts(max_publish_attempts)?;
+
+    emit_cpi!(MaxPublishAttemptsUpdated {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        max_publish_attempts,
+        actor: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_reschedule_policy(
+    ctx: Context<UpdateTwitterAccount>,
+    reschedule_policy: ReschedulePolicy
+) -> Result<()> {
+    ctx.accounts.twitter_account.reschedule_policy = reschedule_policy;
+
+    emit_cpi!(ReschedulePolicyUpdated {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        reschedule_policy,
+        actor: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_expiry_crank_fee(ctx: Context<UpdateTwitterAccount>, expiry_crank_fee: u64) -> Result<()> {
+    ctx.accounts.twitter_account.update_expiry_crank_fee(expiry_crank_fee)?;
+
+    emit_cpi!(ExpiryCrankFeeUpdated {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        expiry_crank_fee,
+        actor: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_required_approvals<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateApprovalPolicy<'info>>,
+    required_approvals: u8
+) -> Result<()> {
+    update_approval_policy(ctx, ApprovalPolicy::Threshold { required: required_approvals })
+}
+
+pub fn update_approval_policy<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateApprovalPolicy<'info>>,
+    approval_policy: ApprovalPolicy
+) -> Result<()> {
+    // Group members prove they are admins through their Member PDAs; only the
+    // program creates those, so the stored Twitter account is trustworthy
+    let twitter_account = &ctx.accounts.twitter_account;
+    let mut admins = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts {
+        let member = Account::<Member>::try_from(info)?;
+        require_keys_eq!(
+            member.twitter_account,
+            twitter_account.key(),
+            HelmError::InvalidTwitterAccount
+        );
+        if member.is_admin(twitter_account.member_epoch) {
+            admins.push(member.wallet);
+        }
+    }
+
+    ctx.accounts.twitter_account.update_approval_policy(approval_policy.clone(), &admins)?;
+
+    emit_cpi!(ApprovalPolicyUpdated {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        approval_policy,
+        actor: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// File: src/instructions/twitter/verify.rs
+
+use anchor_lang::prelude::*;
+use crate::{
+    attestation::{ verification_message, verify_attestation },
+    constants::SERVICE_AUTHORITY,
+    contexts::twitter::VerifyTwitterAccount,
+    events::TwitterAccountVerified,
+    state::AttestationConfig,
+};
+
+pub fn verify(ctx: Context<VerifyTwitterAccount>) -> Result<()> {
+    let twitter_account = &ctx.accounts.twitter_account;
+
+    let signers = AttestationConfig::trusted_signers(
+        ctx.accounts.attestation_config.as_deref(),
+        SERVICE_AUTHORITY
+    );
+    let message = verification_message(
+        &twitter_account.key(),
+        &twitter_account.twitter_id,
+        &twitter_account.owner,
+        &twitter_account.twitter_handle
+    );
+    verify_attestation(&ctx.accounts.instructions, &signers, &message)?;
+
+    // The service vouched for the handle, so any other account still indexed under it
+    // has moved on
+    let twitter_account_key = ctx.accounts.twitter_account.key();
+    ctx.accounts.handle_index.initialize(
+        twitter_account_key,
+        ctx.accounts.twitter_account.twitter_handle.clone(),
+        ctx.bumps.handle_index
+    );
+    ctx.accounts.twitter_account.is_verified = true;
+
+    emit_cpi!(TwitterAccountVerified {
+        twitter_account: ctx.accounts.twitter_account.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
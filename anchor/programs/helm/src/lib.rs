@@ -1,8 +1,10 @@
 // File: src/lib.rs
 
 use anchor_lang::prelude::*;
+use crate::constants::{ ROLE_ADMIN, ROLE_CREATOR };
 use crate::contexts::*;
-use crate::enums::ContentType;
+use crate::enums::{ ContentType, ReschedulePolicy };
+use crate::state::ApprovalPolicy;
 
 pub mod state;
 pub mod contexts;
@@ -10,6 +12,9 @@ pub mod instructions;
 pub mod errors;
 pub mod constants;
 pub mod enums;
+pub mod attestation;
+pub mod events;
+pub mod merkle;
 
 declare_id!("D9nBEe6FjDwub19rBUPUsThMqgBYF4aGCNaYBVcGr2zf");
 
@@ -26,26 +31,152 @@ pub mod helm {
         instructions::twitter::register(ctx, twitter_id, twitter_handle)
     }
 
-    pub fn add_admin(ctx: Context<ManageAdmin>, admin: Pubkey) -> Result<()> {
-        instructions::admin::add(ctx, admin)
+    pub fn update_max_publish_attempts(
+        ctx: Context<UpdateTwitterAccount>,
+        max_publish_attempts: u8
+    ) -> Result<()> {
+        instructions::twitter::update_max_publish_attempts(ctx, max_publish_attempts)
+    }
+
+    pub fn update_reschedule_policy(
+        ctx: Context<UpdateTwitterAccount>,
+        reschedule_policy: ReschedulePolicy
+    ) -> Result<()> {
+        instructions::twitter::update_reschedule_policy(ctx, reschedule_policy)
+    }
+
+    pub fn update_expiry_crank_fee(
+        ctx: Context<UpdateTwitterAccount>,
+        expiry_crank_fee: u64
+    ) -> Result<()> {
+        instructions::twitter::update_expiry_crank_fee(ctx, expiry_crank_fee)
+    }
+
+    pub fn update_required_approvals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateApprovalPolicy<'info>>,
+        required_approvals: u8
+    ) -> Result<()> {
+        instructions::twitter::update_required_approvals(ctx, required_approvals)
+    }
+
+    pub fn update_approval_policy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateApprovalPolicy<'info>>,
+        approval_policy: ApprovalPolicy
+    ) -> Result<()> {
+        instructions::twitter::update_approval_policy(ctx, approval_policy)
+    }
+
+    pub fn add_admin(
+        ctx: Context<ManageMember>,
+        admin: Pubkey,
+        expires_at: Option<i64>
+    ) -> Result<()> {
+        instructions::member::grant(ctx, admin, ROLE_ADMIN, expires_at)
     }
 
-    pub fn remove_admin(ctx: Context<ManageAdmin>, admin: Pubkey) -> Result<()> {
-        instructions::admin::remove(ctx, admin)
+    pub fn remove_admin<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageMember<'info>>,
+        admin: Pubkey
+    ) -> Result<()> {
+        instructions::member::revoke(ctx, admin, ROLE_ADMIN)
     }
 
     pub fn verify_twitter_account(ctx: Context<VerifyTwitterAccount>) -> Result<()> {
-        let twitter_account = &mut ctx.accounts.twitter_account;
-        twitter_account.is_verified = true;
-        Ok(())
+        instructions::twitter::verify(ctx)
+    }
+
+    pub fn reclaim_twitter_account(
+        ctx: Context<ReclaimTwitterAccount>,
+        attestation_expires_at: i64
+    ) -> Result<()> {
+        instructions::twitter::reclaim(ctx, attestation_expires_at)
+    }
+
+    pub fn update_handle(ctx: Context<UpdateTwitterHandle>, new_handle: String) -> Result<()> {
+        instructions::twitter::update_handle(ctx, new_handle)
+    }
+
+    pub fn propose_owner(
+        ctx: Context<UpdateTwitterAccount>,
+        new_owner: Pubkey,
+        expires_at: i64
+    ) -> Result<()> {
+        instructions::twitter::propose_owner(ctx, new_owner, expires_at)
+    }
+
+    pub fn accept_ownership<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AcceptOwnership<'info>>
+    ) -> Result<()> {
+        instructions::twitter::accept_ownership(ctx)
+    }
+
+    pub fn deregister_twitter_account<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeregisterTwitterAccount<'info>>
+    ) -> Result<()> {
+        instructions::twitter::deregister(ctx)
+    }
+
+    pub fn set_attestation_oracle(
+        ctx: Context<SetAttestationOracle>,
+        oracle: Option<Pubkey>
+    ) -> Result<()> {
+        instructions::service::set_attestation_oracle(ctx, oracle)
+    }
+
+    pub fn add_creator(
+        ctx: Context<ManageMember>,
+        creator: Pubkey,
+        expires_at: Option<i64>
+    ) -> Result<()> {
+        instructions::member::grant(ctx, creator, ROLE_CREATOR, expires_at)
+    }
+
+    pub fn remove_creator<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageMember<'info>>,
+        creator: Pubkey
+    ) -> Result<()> {
+        instructions::member::revoke(ctx, creator, ROLE_CREATOR)
+    }
+
+    pub fn grant_roles(
+        ctx: Context<ManageMember>,
+        wallet: Pubkey,
+        roles: u8,
+        expires_at: Option<i64>
+    ) -> Result<()> {
+        instructions::member::grant(ctx, wallet, roles, expires_at)
+    }
+
+    pub fn revoke_roles<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageMember<'info>>,
+        wallet: Pubkey,
+        roles: u8
+    ) -> Result<()> {
+        instructions::member::revoke(ctx, wallet, roles)
     }
 
-    pub fn add_creator(ctx: Context<ManageCreator>, creator: Pubkey) -> Result<()> {
-        instructions::creator::add(ctx, creator)
+    pub fn rotate_admin_key<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RotateAdminKey<'info>>
+    ) -> Result<()> {
+        instructions::member::rotate_admin_key(ctx)
     }
 
-    pub fn remove_creator(ctx: Context<ManageCreator>, creator: Pubkey) -> Result<()> {
-        instructions::creator::remove(ctx, creator)
+    pub fn create_invite(
+        ctx: Context<CreateInvite>,
+        invitee: Pubkey,
+        roles: u8,
+        expires_at: i64,
+        code_hash: Option<[u8; 32]>
+    ) -> Result<()> {
+        instructions::member::create_invite(ctx, invitee, roles, expires_at, code_hash)
+    }
+
+    pub fn accept_invite(ctx: Context<AcceptInvite>, code: Option<String>) -> Result<()> {
+        instructions::member::accept_invite(ctx, code)
+    }
+
+    pub fn revoke_invite(ctx: Context<RevokeInvite>) -> Result<()> {
+        instructions::member::revoke_invite(ctx)
     }
 
     // Update content workflow instructions
@@ -55,26 +186,49 @@ pub mod helm {
         content_hash: [u8; 32],
         scheduled_for: Option<i64>
     ) -> Result<()> {
-        let content = &mut ctx.accounts.content;
+        instructions::content::submit_for_approval(ctx, content_type, content_hash, scheduled_for)
+    }
+
+    pub fn create_draft(
+        ctx: Context<SubmitContentAction>,
+        content_type: ContentType,
+        content_hash: [u8; 32],
+        scheduled_for: Option<i64>
+    ) -> Result<()> {
+        instructions::content::create_draft(ctx, content_type, content_hash, scheduled_for)
+    }
+
+    pub fn update_draft(
+        ctx: Context<DraftAction>,
+        content_type: ContentType,
+        content_hash: [u8; 32],
+        scheduled_for: Option<i64>
+    ) -> Result<()> {
+        instructions::content::update_draft(ctx, content_type, content_hash, scheduled_for)
+    }
 
-        // Initialize content with provided parameters
-        content.initialize(
-            ctx.accounts.twitter_account.key(),
-            ctx.accounts.authority.key(),
-            content_type,
-            content_hash,
-            scheduled_for,
-            ctx.bumps.content,
-            Clock::get()?.unix_timestamp
-        );
+    pub fn submit_draft(ctx: Context<DraftAction>) -> Result<()> {
+        instructions::content::submit_draft(ctx)
+    }
 
-        instructions::content::submit_for_approval(ctx)
+    pub fn revise_content(ctx: Context<ReviseContent>, content_hash: [u8; 32]) -> Result<()> {
+        instructions::content::revise_content(ctx, content_hash)
     }
 
-    pub fn approve_content(ctx: Context<ContentAction>) -> Result<()> {
+    pub fn approve_content<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ContentAction<'info>>
+    ) -> Result<()> {
         instructions::content::approve_content(ctx)
     }
 
+    pub fn revoke_approval(ctx: Context<ContentAction>) -> Result<()> {
+        instructions::content::revoke_approval(ctx)
+    }
+
+    pub fn reschedule_content(ctx: Context<ContentAction>, scheduled_for: i64) -> Result<()> {
+        instructions::content::reschedule_content(ctx, scheduled_for)
+    }
+
     pub fn reject_content(ctx: Context<ContentAction>, reason: String) -> Result<()> {
         instructions::content::reject_content(ctx, reason)
     }
@@ -82,4 +236,60 @@ pub mod helm {
     pub fn cancel_content(ctx: Context<ContentAction>) -> Result<()> {
         instructions::content::cancel_content(ctx)
     }
+
+    pub fn close_content(ctx: Context<CloseContent>) -> Result<()> {
+        instructions::content::close_content(ctx)
+    }
+
+    // Permissionless
+    pub fn close_content_revision(ctx: Context<CloseContentRevision>) -> Result<()> {
+        instructions::content::close_content_revision(ctx)
+    }
+
+    // Permissionless crank
+    pub fn expire_content(ctx: Context<ExpireContent>) -> Result<()> {
+        instructions::content::expire_content(ctx)
+    }
+
+    pub fn prune_expired_members<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneExpiredMembers<'info>>
+    ) -> Result<()> {
+        instructions::member::prune_expired(ctx)
+    }
+
+    // Service authority (publisher) instructions
+    pub fn mark_published<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ServiceContentAction<'info>>,
+        tweet_ids: Vec<u64>
+    ) -> Result<()> {
+        instructions::content::mark_published(ctx, tweet_ids)
+    }
+
+    pub fn mark_thread_item_published<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ServiceContentAction<'info>>,
+        index: u8,
+        tweet_hash: [u8; 32],
+        proof: Vec<[u8; 32]>,
+        tweet_id: u64
+    ) -> Result<()> {
+        instructions::content::mark_thread_item_published(ctx, index, tweet_hash, proof, tweet_id)
+    }
+
+    pub fn requeue_content<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ServiceContentAction<'info>>
+    ) -> Result<()> {
+        instructions::content::requeue_content(ctx)
+    }
+
+    pub fn mark_failed(
+        ctx: Context<ServiceContentAction>,
+        reason: String,
+        next_retry_at: Option<i64>
+    ) -> Result<()> {
+        instructions::content::mark_failed(ctx, reason, next_retry_at)
+    }
+
+    pub fn retry_content(ctx: Context<RetryContentAction>, requeue_for_approval: bool) -> Result<()> {
+        instructions::content::retry_content(ctx, requeue_for_approval)
+    }
 }
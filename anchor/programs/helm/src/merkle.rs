@@ -0,0 +1,52 @@
+// File: src/merkle.rs
+use anchor_lang::solana_program::keccak::hashv;
+
+// Prefixes keep a leaf from being passed off as an inner node and vice versa
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+pub fn leaf_hash(tweet_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, tweet_hash]).to_bytes()
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Checks that `tweet_hash` sits at `index` of a thread with `leaf_count` tweets
+/// committed to by `root`. Pairs are hashed left to right in thread order; a node
+/// without a sibling is carried up to the next level unchanged.
+pub fn verify_proof(
+    root: &[u8; 32],
+    leaf_count: usize,
+    index: usize,
+    tweet_hash: &[u8; 32],
+    proof: &[[u8; 32]]
+) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+
+    let mut node = leaf_hash(tweet_hash);
+    let mut index = index;
+    let mut width = leaf_count;
+    let mut siblings = proof.iter();
+
+    while width > 1 {
+        let sibling_index = index ^ 1;
+        if sibling_index < width {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            node = if sibling_index > index {
+                node_hash(&node, sibling)
+            } else {
+                node_hash(sibling, &node)
+            };
+        }
+        index /= 2;
+        width = width.div_ceil(2);
+    }
+
+    siblings.next().is_none() && &node == root
+}
@@ -0,0 +1,138 @@
+// File: src/state/approval_policy.rs
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        MAX_APPROVAL_GROUPS,
+        MAX_APPROVAL_GROUP_NAME_LENGTH,
+        MAX_CONTENT_APPROVALS,
+        MAX_GROUP_MEMBERS,
+        MAX_REQUIRED_APPROVALS,
+    },
+    errors::HelmError,
+};
+
+/// A named set of admins, e.g. legal or marketing
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct ApprovalGroup {
+    pub name: String,
+    pub members: Vec<Pubkey>,
+}
+
+/// How many admin approvals content needs before it is approved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum ApprovalPolicy {
+    /// At least `required` approvals from current admins (N of M)
+    Threshold { required: u8 },
+    /// At least `percent`% of current admins, rounded up
+    Percentage { percent: u8 },
+    /// At least one approval from each group
+    Groups { groups: Vec<ApprovalGroup> },
+}
+
+impl ApprovalPolicy {
+    /// `admins` only needs to cover group members; `admin_count` is the full count
+    pub fn validate(&self, admin_count: u32, admins: &[Pubkey]) -> Result<()> {
+        match self {
+            ApprovalPolicy::Threshold { required } => {
+                require!(
+                    *required > 0 && *required <= MAX_REQUIRED_APPROVALS,
+                    HelmError::InvalidRequiredApprovals
+                );
+            }
+            ApprovalPolicy::Percentage { percent } => {
+                require!(*percent > 0 && *percent <= 100, HelmError::InvalidApprovalPolicy);
+                require!(
+                    self.within_approval_capacity(admin_count),
+                    HelmError::TooManyRequiredApprovals
+                );
+            }
+            ApprovalPolicy::Groups { groups } => {
+                require!(
+                    !groups.is_empty() && groups.len() <= MAX_APPROVAL_GROUPS,
+                    HelmError::InvalidApprovalPolicy
+                );
+                for group in groups {
+                    require!(
+                        !group.name.is_empty() &&
+                            group.name.len() <= MAX_APPROVAL_GROUP_NAME_LENGTH,
+                        HelmError::InvalidApprovalPolicy
+                    );
+                    require!(
+                        !group.members.is_empty() && group.members.len() <= MAX_GROUP_MEMBERS,
+                        HelmError::InvalidApprovalPolicy
+                    );
+                    require!(
+                        group.members.iter().all(|member| admins.contains(member)),
+                        HelmError::AdminDoesNotExist
+                    );
+                }
+            }
+        }
+
+        require!(self.is_reachable(admin_count), HelmError::RequiredApprovalsUnreachable);
+        Ok(())
+    }
+
+    /// Whether `admin_count` admins could satisfy this policy at all. Group
+    /// members are kept to current admins, so a non-empty group is reachable.
+    pub fn is_reachable(&self, admin_count: u32) -> bool {
+        match self {
+            ApprovalPolicy::Threshold { required } => admin_count >= (*required as u32),
+            ApprovalPolicy::Percentage { .. } =>
+                admin_count > 0 && self.within_approval_capacity(admin_count),
+            ApprovalPolicy::Groups { groups } => groups.iter().all(|group| !group.members.is_empty()),
+        }
+    }
+
+    /// Whether the approvals needed with `admin_count` admins fit on a content account.
+    /// Only a percentage grows with the admin count; the other policies are bounded.
+    pub fn within_approval_capacity(&self, admin_count: u32) -> bool {
+        match self {
+            ApprovalPolicy::Percentage { percent } =>
+                Self::percentage_needed(*percent, admin_count) <= MAX_CONTENT_APPROVALS,
+            _ => true,
+        }
+    }
+
+    fn percentage_needed(percent: u8, admin_count: u32) -> usize {
+        ((admin_count as usize) * (percent as usize)).div_ceil(100).max(1)
+    }
+
+    /// Drops `admin` from every group; an emptied group makes the policy unreachable
+    pub fn remove_member(&mut self, admin: &Pubkey) {
+        if let ApprovalPolicy::Groups { groups } = self {
+            for group in groups.iter_mut() {
+                group.members.retain(|member| member != admin);
+            }
+        }
+    }
+
+    /// Moves any group membership of `old` over to `new`
+    pub fn replace_member(&mut self, old: &Pubkey, new: Pubkey) {
+        if let ApprovalPolicy::Groups { groups } = self {
+            for group in groups.iter_mut() {
+                if group.members.contains(&new) {
+                    group.members.retain(|member| member != old);
+                } else if let Some(member) = group.members.iter_mut().find(|m| *m == old) {
+                    *member = new;
+                }
+            }
+        }
+    }
+
+    /// `approvals` must only hold current admins; see `approvals_in_force`
+    pub fn is_satisfied(&self, approvals: &[Pubkey], admin_count: u32) -> bool {
+        let approval_count = approvals.len();
+
+        match self {
+            ApprovalPolicy::Threshold { required } => approval_count >= (*required as usize),
+            ApprovalPolicy::Percentage { percent } =>
+                approval_count >= Self::percentage_needed(*percent, admin_count),
+            ApprovalPolicy::Groups { groups } =>
+                groups
+                    .iter()
+                    .all(|group| group.members.iter().any(|member| approvals.contains(member))),
+        }
+    }
+}
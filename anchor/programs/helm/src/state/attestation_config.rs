@@ -0,0 +1,23 @@
+// File: src/state/attestation_config.rs
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct AttestationConfig {
+    /// Additional key trusted to sign attestations besides the service authority
+    pub oracle: Option<Pubkey>,
+    /// Last time the oracle was changed
+    pub updated_at: i64,
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl AttestationConfig {
+    /// Keys whose attestations are currently accepted
+    pub fn trusted_signers(config: Option<&Self>, service_authority: Pubkey) -> Vec<Pubkey> {
+        let mut signers = vec![service_authority];
+        if let Some(oracle) = config.and_then(|config| config.oracle) {
+            signers.push(oracle);
+        }
+        signers
+    }
+}
@@ -2,31 +2,62 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{ CONTENT_SEED, MAX_SCHEDULE_DELAY, MIN_SCHEDULE_DELAY },
-    enums::{ ContentStatus, ContentType },
+    constants::{
+        CONTENT_SEED,
+        DEFAULT_RETRY_DELAY,
+        EXPIRATION_GRACE_PERIOD,
+        MAX_CONTENT_APPROVALS,
+        MAX_FAILURE_REASON_LENGTH,
+        MAX_REJECTION_REASON_LENGTH,
+        MAX_SCHEDULE_DELAY,
+        MIN_SCHEDULE_DELAY,
+    },
+    enums::{ ContentStatus, ContentType, ReschedulePolicy },
     errors::HelmError,
+    merkle,
+    state::ApprovalPolicy,
 };
 
 #[account]
 pub struct Content {
     /// Reference to the Twitter account this content belongs to
     pub twitter_account: Pubkey,
+    /// Per-account sequence number used to derive this PDA
+    pub sequence: u64,
     /// Content creator's public key
     pub author: Pubkey,
-    /// Content type (single tweet or thread)
+    /// Wallet that paid for this account; receives the rent when it is closed
+    pub payer: Pubkey,
+    /// Content type (tweet, thread, reply, quote, poll or media)
     pub content_type: ContentType,
     /// Keccak256 hash of the content
     pub content_hash: [u8; 32],
+    /// Number of times the content was revised after a rejection
+    pub revision: u16,
     /// Unix timestamp for scheduled publication
     pub scheduled_for: Option<i64>,
     /// Current content status
     pub status: ContentStatus,
     /// List of admin approvals
     pub approvals: Vec<Pubkey>,
+    /// Membership epoch the approvals were given in; a reclaim makes them stale
+    pub approvals_epoch: u32,
     /// Reason for rejection if rejected
     pub rejection_reason: Option<String>,
     /// Reason for failure if failed
     pub failure_reason: Option<String>,
+    /// Tweet IDs returned by Twitter once the content was published
+    pub published_tweet_ids: Vec<u64>,
+    /// Bitmap of thread tweets already posted, bit `i` for tweet `i`
+    pub published_items: u64,
+    /// When the content was published
+    pub published_at: Option<i64>,
+    /// Number of failed publication attempts
+    pub publish_attempts: u8,
+    /// Earliest time the service may retry publication after a failure
+    pub next_retry_at: Option<i64>,
+    /// Admin who rescheduled the content, while their change awaits re-approval
+    pub rescheduled_by: Option<Pubkey>,
     /// When the content was created
     pub created_at: i64,
     /// Last time content was modified
@@ -37,23 +68,21 @@ pub struct Content {
 
 impl Content {
     // Account initialization and PDA helpers
-    pub fn seeds(
-        twitter_account: &Pubkey,
-        author: &Pubkey,
-        content_hash: &[u8; 32]
-    ) -> Vec<Vec<u8>> {
+    pub fn seeds(twitter_account: &Pubkey, sequence: u64) -> Vec<Vec<u8>> {
         vec![
             CONTENT_SEED.to_vec(),
             twitter_account.to_bytes().to_vec(),
-            author.to_bytes().to_vec(),
-            content_hash.to_vec()
+            sequence.to_le_bytes().to_vec()
         ]
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         twitter_account: Pubkey,
+        sequence: u64,
         author: Pubkey,
+        payer: Pubkey,
         content_type: ContentType,
         content_hash: [u8; 32],
         scheduled_for: Option<i64>,
@@ -61,21 +90,36 @@ impl Content {
         timestamp: i64
     ) {
         self.twitter_account = twitter_account;
+        self.sequence = sequence;
         self.author = author;
+        self.payer = payer;
         self.content_type = content_type;
         self.content_hash = content_hash;
+        self.revision = 0;
         self.scheduled_for = scheduled_for;
         self.status = ContentStatus::Draft;
         self.approvals = Vec::new();
+        self.approvals_epoch = 0;
         self.rejection_reason = None;
         self.failure_reason = None;
+        self.published_tweet_ids = Vec::new();
+        self.published_items = 0;
+        self.published_at = None;
+        self.publish_attempts = 0;
+        self.next_retry_at = None;
+        self.rescheduled_by = None;
         self.created_at = timestamp;
         self.updated_at = timestamp;
         self.bump = bump;
     }
 
     // Main content workflow methods
-    pub fn submit(&mut self, authority: Pubkey, required_approvals: u8) -> Result<()> {
+    pub fn submit(
+        &mut self,
+        authority: Pubkey,
+        policy: &ApprovalPolicy,
+        admin_count: u32
+    ) -> Result<()> {
         require!(self.status == ContentStatus::Draft, HelmError::InvalidContentStatus);
 
         if let Some(scheduled_time) = self.scheduled_for {
@@ -91,7 +135,7 @@ impl Content {
             self.approvals.push(authority);
 
             // Auto-approve if we have enough approvals
-            if self.approvals.len() >= (required_approvals as usize) {
+            if policy.is_satisfied(&self.approvals, admin_count) {
                 self.transition_to(ContentStatus::Approved)?;
             }
         }
@@ -99,17 +143,19 @@ impl Content {
         Ok(())
     }
 
-    pub fn approve(&mut self, approver: Pubkey, required_approvals: u8) -> Result<()> {
+    pub fn approve(
+        &mut self,
+        approver: Pubkey,
+        policy: &ApprovalPolicy,
+        admin_count: u32
+    ) -> Result<()> {
         require!(self.status == ContentStatus::PendingApproval, HelmError::InvalidContentStatus);
         require!(!self.approvals.contains(&approver), HelmError::AlreadyApproved);
-        require!(
-            self.approvals.len() < (required_approvals as usize) * 2,
-            HelmError::InvalidContentStatus
-        );
+        require!(self.approvals.len() < MAX_CONTENT_APPROVALS, HelmError::InvalidContentStatus);
 
         self.approvals.push(approver);
 
-        if self.approvals.len() >= (required_approvals as usize) {
+        if policy.is_satisfied(&self.approvals, admin_count) {
             self.transition_to(ContentStatus::Approved)?;
         }
 
@@ -117,20 +163,317 @@ impl Content {
         Ok(())
     }
 
+    pub fn revoke_approval(
+        &mut self,
+        approver: &Pubkey,
+        policy: &ApprovalPolicy,
+        admin_count: u32
+    ) -> Result<()> {
+        require!(
+            matches!(self.status, ContentStatus::PendingApproval | ContentStatus::Approved),
+            HelmError::InvalidContentStatus
+        );
+        // Approvals of a thread that has started posting are already spent
+        require!(self.published_items == 0, HelmError::InvalidContentStatus);
+        require!(self.drop_approval(approver, policy, admin_count)?, HelmError::ApprovalNotFound);
+        Ok(())
+    }
+
+    /// Removes `admin`'s approval if present, sending approved content back into
+    /// review once the policy is no longer met. A thread that has started posting
+    /// stays approved so it can be finished. Returns whether anything changed.
+    pub fn drop_approval(
+        &mut self,
+        admin: &Pubkey,
+        policy: &ApprovalPolicy,
+        admin_count: u32
+    ) -> Result<bool> {
+        if !self.approvals.contains(admin) {
+            return Ok(false);
+        }
+
+        self.approvals.retain(|x| x != admin);
+
+        // Drop back into review once the threshold is no longer met
+        if
+            self.status == ContentStatus::Approved &&
+            self.published_items == 0 &&
+            !policy.is_satisfied(&self.approvals, admin_count)
+        {
+            self.transition_to(ContentStatus::PendingApproval)?;
+        }
+
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(true)
+    }
+
+    /// Carries `old`'s approval over to the admin's rotated key. If `new` already
+    /// approved, the duplicate is dropped instead. Returns whether anything changed.
+    pub fn replace_admin(
+        &mut self,
+        old: &Pubkey,
+        new: Pubkey,
+        policy: &ApprovalPolicy,
+        admin_count: u32
+    ) -> Result<bool> {
+        let mut changed = false;
+        if self.rescheduled_by == Some(*old) {
+            self.rescheduled_by = Some(new);
+            changed = true;
+        }
+
+        if self.approvals.contains(&new) {
+            return Ok(self.drop_approval(old, policy, admin_count)? || changed);
+        }
+        if let Some(approval) = self.approvals.iter_mut().find(|x| *x == old) {
+            *approval = new;
+            changed = true;
+        }
+
+        if changed {
+            self.updated_at = Clock::get()?.unix_timestamp;
+        }
+        Ok(changed)
+    }
+
+    /// Discards approvals given by a previous generation of admins
+    pub fn sync_approvals_epoch(&mut self, member_epoch: u32) {
+        if self.approvals_epoch != member_epoch {
+            self.approvals.clear();
+            self.rescheduled_by = None;
+            self.approvals_epoch = member_epoch;
+        }
+    }
+
     pub fn reject(&mut self, reason: String) -> Result<()> {
         require!(self.status == ContentStatus::PendingApproval, HelmError::InvalidContentStatus);
+        require!(reason.len() <= MAX_REJECTION_REASON_LENGTH, HelmError::ReasonTooLong);
         self.transition_to(ContentStatus::Rejected)?;
         self.rejection_reason = Some(reason);
         self.updated_at = Clock::get()?.unix_timestamp;
         Ok(())
     }
 
+    pub fn update_draft(
+        &mut self,
+        content_type: ContentType,
+        content_hash: [u8; 32],
+        scheduled_for: Option<i64>,
+        timestamp: i64
+    ) -> Result<()> {
+        require!(self.status == ContentStatus::Draft, HelmError::InvalidContentStatus);
+        content_type.validate()?;
+
+        self.content_type = content_type;
+        self.content_hash = content_hash;
+        self.scheduled_for = scheduled_for;
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    pub fn revise(&mut self, content_hash: [u8; 32], timestamp: i64) -> Result<()> {
+        require!(self.status == ContentStatus::Rejected, HelmError::InvalidContentStatus);
+        require!(content_hash != self.content_hash, HelmError::InvalidContentHash);
+        // Tweets already posted cannot be taken back by a revision
+        require!(self.published_items == 0, HelmError::InvalidContentStatus);
+
+        // Moving back to Draft clears approvals and the rejection reason
+        self.transition_to(ContentStatus::Draft)?;
+        self.content_hash = content_hash;
+        self.revision = self.revision.checked_add(1).ok_or(HelmError::MaxRevisionsReached)?;
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    pub fn publish(&mut self, tweet_ids: Vec<u64>, timestamp: i64) -> Result<()> {
+        require!(self.status == ContentStatus::Approved, HelmError::InvalidContentStatus);
+
+        if let Some(scheduled_time) = self.scheduled_for {
+            require!(timestamp >= scheduled_time, HelmError::ScheduleTimeNotReached);
+        }
+
+        self.validate_published_tweet_ids(&tweet_ids)?;
+
+        // Tweets of a partially posted thread must keep the IDs already recorded
+        let already_posted = self.published_tweet_ids
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.is_item_published(*index as u8));
+        for (index, tweet_id) in already_posted {
+            require!(tweet_ids[index] == *tweet_id, HelmError::InvalidPublishedTweetIds);
+        }
+
+        self.transition_to(ContentStatus::Published)?;
+        self.published_tweet_ids = tweet_ids;
+        self.published_at = Some(timestamp);
+        self.next_retry_at = None;
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    /// Records one posted tweet of a thread and returns whether the whole thread is now out
+    pub fn publish_thread_item(
+        &mut self,
+        index: u8,
+        tweet_hash: &[u8; 32],
+        proof: &[[u8; 32]],
+        tweet_id: u64,
+        timestamp: i64
+    ) -> Result<bool> {
+        require!(self.status == ContentStatus::Approved, HelmError::InvalidContentStatus);
+
+        if let Some(scheduled_time) = self.scheduled_for {
+            require!(timestamp >= scheduled_time, HelmError::ScheduleTimeNotReached);
+        }
+
+        self.verify_thread_item(index, tweet_hash, proof)?;
+        require!(tweet_id > 0, HelmError::InvalidPublishedTweetIds);
+
+        // Each tweet replies to the previous one, so posting resumes at the first gap
+        require!(self.first_unpublished_item() == Some(index), HelmError::ThreadItemOutOfOrder);
+
+        let tweet_count = self.content_type.tweet_count();
+        if self.published_tweet_ids.len() != tweet_count {
+            self.published_tweet_ids = vec![0; tweet_count];
+        }
+        self.published_tweet_ids[index as usize] = tweet_id;
+        self.published_items |= 1u64 << index;
+        self.updated_at = timestamp;
+
+        if self.first_unpublished_item().is_some() {
+            return Ok(false);
+        }
+
+        self.transition_to(ContentStatus::Published)?;
+        self.published_at = Some(timestamp);
+        self.next_retry_at = None;
+        self.updated_at = timestamp;
+        Ok(true)
+    }
+
+    pub fn reschedule(
+        &mut self,
+        scheduled_for: i64,
+        policy: ReschedulePolicy,
+        rescheduled_by: Pubkey,
+        clock: &Clock
+    ) -> Result<()> {
+        require!(
+            matches!(self.status, ContentStatus::PendingApproval | ContentStatus::Approved),
+            HelmError::InvalidContentStatus
+        );
+        // Once a thread has started posting its schedule no longer applies
+        require!(self.published_items == 0, HelmError::InvalidContentStatus);
+        require!(self.scheduled_for != Some(scheduled_for), HelmError::InvalidScheduleTime);
+
+        self.scheduled_for = Some(scheduled_for);
+        self.validate_scheduled_time(clock)?;
+
+        match policy {
+            ReschedulePolicy::KeepApprovals => {}
+            ReschedulePolicy::RequireReapproval => {
+                self.rescheduled_by = Some(rescheduled_by);
+            }
+            ReschedulePolicy::ResetApprovals => {
+                self.approvals.clear();
+                self.rescheduled_by = None;
+            }
+        }
+
+        if policy != ReschedulePolicy::KeepApprovals && self.status == ContentStatus::Approved {
+            self.transition_to(ContentStatus::PendingApproval)?;
+        }
+
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    pub fn expire(&mut self, timestamp: i64) -> Result<()> {
+        require!(
+            matches!(self.status, ContentStatus::PendingApproval | ContentStatus::Approved),
+            HelmError::InvalidContentStatus
+        );
+        // A thread that has started posting is finished, not expired
+        require!(self.published_items == 0, HelmError::InvalidContentStatus);
+
+        let scheduled_for = self.scheduled_for.ok_or(HelmError::ContentNotExpired)?;
+        let expires_at = scheduled_for.saturating_add(EXPIRATION_GRACE_PERIOD);
+        require!(timestamp >= expires_at, HelmError::ContentNotExpired);
+
+        self.transition_to(ContentStatus::Expired)?;
+        self.next_retry_at = None;
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    pub fn fail(
+        &mut self,
+        reason: String,
+        next_retry_at: Option<i64>,
+        max_attempts: u8,
+        timestamp: i64
+    ) -> Result<()> {
+        require!(self.status == ContentStatus::Approved, HelmError::InvalidContentStatus);
+        require!(reason.len() <= MAX_FAILURE_REASON_LENGTH, HelmError::ReasonTooLong);
+
+        let next_retry_at = next_retry_at.unwrap_or(
+            timestamp.checked_add(DEFAULT_RETRY_DELAY).ok_or(HelmError::InvalidRetryTime)?
+        );
+        require!(next_retry_at > timestamp, HelmError::InvalidRetryTime);
+
+        self.transition_to(ContentStatus::Failed)?;
+        self.failure_reason = Some(reason);
+        self.publish_attempts = self.publish_attempts.saturating_add(1);
+
+        // No further retries are scheduled once the attempt budget is spent
+        self.next_retry_at = if self.publish_attempts < max_attempts {
+            Some(next_retry_at)
+        } else {
+            None
+        };
+
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
+    pub fn retry(
+        &mut self,
+        requeue_for_approval: bool,
+        max_attempts: u8,
+        timestamp: i64
+    ) -> Result<()> {
+        require!(self.status == ContentStatus::Failed, HelmError::InvalidContentStatus);
+
+        if requeue_for_approval {
+            // A thread that has started posting can only be retried where it left off
+            require!(self.published_items == 0, HelmError::InvalidContentStatus);
+
+            // Send back to reviewers with a fresh approval round and attempt budget
+            self.transition_to(ContentStatus::PendingApproval)?;
+            self.approvals.clear();
+            self.rescheduled_by = None;
+            self.publish_attempts = 0;
+        } else {
+            require!(self.publish_attempts < max_attempts, HelmError::MaxPublishAttemptsReached);
+            if let Some(next_retry_at) = self.next_retry_at {
+                require!(timestamp >= next_retry_at, HelmError::RetryNotReady);
+            }
+            self.transition_to(ContentStatus::Approved)?;
+        }
+
+        self.failure_reason = None;
+        self.next_retry_at = None;
+        self.updated_at = timestamp;
+        Ok(())
+    }
+
     // State transition and validation methods
     pub fn transition_to(&mut self, new_status: ContentStatus) -> Result<()> {
         require!(self.can_transition_to(new_status), HelmError::InvalidStateTransition);
 
         if new_status == ContentStatus::Draft {
             self.approvals.clear();
+            self.rescheduled_by = None;
             self.rejection_reason = None;
             self.failure_reason = None;
         }
@@ -166,6 +509,47 @@ impl Content {
         Ok(())
     }
 
+    pub fn validate_published_tweet_ids(&self, tweet_ids: &[u64]) -> Result<()> {
+        let expected = self.content_type.tweet_count();
+
+        require!(tweet_ids.len() == expected, HelmError::InvalidPublishedTweetIds);
+        require!(tweet_ids.iter().all(|&id| id > 0), HelmError::InvalidPublishedTweetIds);
+        Ok(())
+    }
+
+    /// Proves that `tweet_hash` is the tweet at `index` of the approved thread
+    pub fn verify_thread_item(
+        &self,
+        index: u8,
+        tweet_hash: &[u8; 32],
+        proof: &[[u8; 32]]
+    ) -> Result<()> {
+        let ContentType::Thread { tweet_count, tweets_root } = &self.content_type else {
+            return err!(HelmError::InvalidContentType);
+        };
+
+        require!(
+            merkle::verify_proof(
+                tweets_root,
+                *tweet_count as usize,
+                index as usize,
+                tweet_hash,
+                proof
+            ),
+            HelmError::InvalidThreadProof
+        );
+        Ok(())
+    }
+
+    pub fn is_item_published(&self, index: u8) -> bool {
+        self.published_items & (1u64 << index) != 0
+    }
+
+    /// The next thread tweet the service should post, if any remain
+    pub fn first_unpublished_item(&self) -> Option<u8> {
+        (0..self.content_type.tweet_count() as u8).find(|&index| !self.is_item_published(index))
+    }
+
     // State check helpers
     pub fn can_transition_to(&self, new_status: ContentStatus) -> bool {
         match (self.status, new_status) {
@@ -177,28 +561,38 @@ impl Content {
             (ContentStatus::PendingApproval, ContentStatus::Approved) => true,
             (ContentStatus::PendingApproval, ContentStatus::Rejected) => true,
             (ContentStatus::PendingApproval, ContentStatus::Canceled) => true,
+            (ContentStatus::PendingApproval, ContentStatus::Expired) => true,
 
             // From Approved
             (ContentStatus::Approved, ContentStatus::Published) => true,
             (ContentStatus::Approved, ContentStatus::Failed) => true,
             (ContentStatus::Approved, ContentStatus::Canceled) => true,
+            (ContentStatus::Approved, ContentStatus::PendingApproval) => true,
+            (ContentStatus::Approved, ContentStatus::Expired) => true,
 
-            // From Rejected/Failed - allow retry
+            // From Rejected/Failed - allow retry; admins may also drop rejected
+            // content whose author will never revise it
             (ContentStatus::Rejected, ContentStatus::Draft) => true,
+            (ContentStatus::Rejected, ContentStatus::Canceled) => true,
             (ContentStatus::Failed, ContentStatus::Draft) => true,
+            (ContentStatus::Failed, ContentStatus::PendingApproval) => true,
+            (ContentStatus::Failed, ContentStatus::Approved) => true,
+            (ContentStatus::Failed, ContentStatus::Canceled) => true,
 
             // Terminal states
             (ContentStatus::Published, _) => false,
             (ContentStatus::Canceled, _) => false,
+            (ContentStatus::Expired, _) => false,
 
             _ => false,
         }
     }
 
+    // Failed content can still be retried, so it is not terminal
     pub fn is_terminal(&self) -> bool {
         matches!(
             self.status,
-            ContentStatus::Published | ContentStatus::Failed | ContentStatus::Canceled
+            ContentStatus::Published | ContentStatus::Canceled | ContentStatus::Expired
         )
     }
 
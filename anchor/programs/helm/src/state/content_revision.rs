@@ -0,0 +1,47 @@
+// File: src/state/content_revision.rs
+use anchor_lang::prelude::*;
+
+use crate::{ enums::ContentType, state::Content };
+
+#[account]
+pub struct ContentRevision {
+    /// The content account this revision belongs to
+    pub content: Pubkey,
+    /// The Twitter account the content belongs to
+    pub twitter_account: Pubkey,
+    /// Revision number this snapshot was taken from
+    pub revision: u16,
+    /// Content type of the superseded revision
+    pub content_type: ContentType,
+    /// Keccak256 hash of the superseded content
+    pub content_hash: [u8; 32],
+    /// Reason the superseded revision was rejected
+    pub rejection_reason: Option<String>,
+    /// Wallet that submitted the replacement revision
+    pub revised_by: Pubkey,
+    /// When the revision was archived
+    pub created_at: i64,
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl ContentRevision {
+    pub fn initialize(
+        &mut self,
+        content_key: Pubkey,
+        content: &Content,
+        revised_by: Pubkey,
+        timestamp: i64,
+        bump: u8
+    ) {
+        self.content = content_key;
+        self.twitter_account = content.twitter_account;
+        self.revision = content.revision;
+        self.content_type = content.content_type.clone();
+        self.content_hash = content.content_hash;
+        self.rejection_reason = content.rejection_reason.clone();
+        self.revised_by = revised_by;
+        self.created_at = timestamp;
+        self.bump = bump;
+    }
+}
@@ -0,0 +1,46 @@
+// File: src/state/handle_index.rs
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct HandleIndex {
+    /// The Twitter account currently using this handle
+    pub twitter_account: Pubkey,
+    /// Handle as registered, before lowercasing for the seed
+    pub handle: String,
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl HandleIndex {
+    pub fn initialize(&mut self, twitter_account: Pubkey, handle: String, bump: u8) {
+        self.twitter_account = twitter_account;
+        self.handle = handle;
+        self.bump = bump;
+    }
+
+    /// Closes the index at `info` into `destination` if it still points at `twitter_account`.
+    /// An index that was never created, or that another account has since taken over, is left alone.
+    pub fn release<'info>(
+        info: &AccountInfo<'info>,
+        twitter_account: &Pubkey,
+        destination: &AccountInfo<'info>
+    ) -> Result<()> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(());
+        }
+        let index = HandleIndex::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        if index.twitter_account != *twitter_account {
+            return Ok(());
+        }
+
+        let refunded = destination
+            .lamports()
+            .checked_add(info.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **destination.try_borrow_mut_lamports()? = refunded;
+        **info.try_borrow_mut_lamports()? = 0;
+        info.assign(&System::id());
+        info.realloc(0, false)?;
+        Ok(())
+    }
+}
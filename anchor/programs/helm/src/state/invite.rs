@@ -0,0 +1,65 @@
+// File: src/state/invite.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hash;
+
+/// Pending offer of roles to a wallet; the wallet only becomes a member once it accepts
+#[account]
+pub struct Invite {
+    /// The Twitter account the invite is for
+    pub twitter_account: Pubkey,
+    /// The only wallet that may accept the invite
+    pub invitee: Pubkey,
+    /// Bitmask of ROLE_* constants granted on acceptance
+    pub roles: u8,
+    /// Keccak256 hash of an invite code the invitee must present, if any
+    pub code_hash: Option<[u8; 32]>,
+    /// Twitter account membership epoch the invite was created in; reclaims void it
+    pub epoch: u32,
+    /// Who created the invite
+    pub invited_by: Pubkey,
+    /// When the invite was created
+    pub created_at: i64,
+    /// When the invite can no longer be accepted
+    pub expires_at: i64,
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl Invite {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        twitter_account: Pubkey,
+        invitee: Pubkey,
+        roles: u8,
+        code_hash: Option<[u8; 32]>,
+        epoch: u32,
+        invited_by: Pubkey,
+        timestamp: i64,
+        expires_at: i64,
+        bump: u8
+    ) {
+        self.twitter_account = twitter_account;
+        self.invitee = invitee;
+        self.roles = roles;
+        self.code_hash = code_hash;
+        self.epoch = epoch;
+        self.invited_by = invited_by;
+        self.created_at = timestamp;
+        self.expires_at = expires_at;
+        self.bump = bump;
+    }
+
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        timestamp >= self.expires_at
+    }
+
+    /// Invites without a code hash accept any (or no) code
+    pub fn matches_code(&self, code: Option<&str>) -> bool {
+        match (self.code_hash, code) {
+            (None, _) => true,
+            (Some(code_hash), Some(code)) => hash(code.as_bytes()).to_bytes() == code_hash,
+            (Some(_), None) => false,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// File: src/state/member.rs
+use anchor_lang::prelude::*;
+
+use crate::constants::{ ROLE_ADMIN, ROLE_CREATOR, ROLE_OWNER };
+
+/// One wallet's roles on a Twitter account, stored at its own PDA
+#[account]
+pub struct Member {
+    /// The Twitter account this membership belongs to
+    pub twitter_account: Pubkey,
+    /// The member's wallet
+    pub wallet: Pubkey,
+    /// Bitmask of ROLE_* constants
+    pub roles: u8,
+    /// Twitter account membership epoch this record was granted in; reclaims bump it
+    pub epoch: u32,
+    /// Who granted the membership
+    pub added_by: Pubkey,
+    /// When the membership was granted
+    pub added_at: i64,
+    /// When every role lapses, for contractors and agency staff; None never expires
+    pub expires_at: Option<i64>,
+    /// Who paid the record's rent and gets it back when the record is closed
+    pub payer: Pubkey,
+    /// Bump for PDA derivation
+    pub bump: u8,
+}
+
+impl Member {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        twitter_account: Pubkey,
+        wallet: Pubkey,
+        epoch: u32,
+        added_by: Pubkey,
+        payer: Pubkey,
+        timestamp: i64,
+        bump: u8
+    ) {
+        // A reused record keeps refunding whoever paid for it in the first place
+        if self.is_new() {
+            self.payer = payer;
+        }
+        self.twitter_account = twitter_account;
+        self.wallet = wallet;
+        self.roles = 0;
+        self.epoch = epoch;
+        self.added_by = added_by;
+        self.added_at = timestamp;
+        self.expires_at = None;
+        self.bump = bump;
+    }
+
+    /// True for a record `init_if_needed` has only just created
+    pub fn is_new(&self) -> bool {
+        self.twitter_account == Pubkey::default()
+    }
+
+    /// Records from an earlier epoch, stripped of every role or past their expiry grant nothing
+    pub fn is_active(&self, member_epoch: u32) -> bool {
+        self.is_counted(member_epoch) && !self.is_expired()
+    }
+
+    /// Still reflected in the account's admin count and approval groups; an expired
+    /// member stays counted until it is pruned, though approval policies skip it
+    pub fn is_counted(&self, member_epoch: u32) -> bool {
+        self.roles != 0 && self.epoch == member_epoch
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| {
+            Clock::get().map_or(true, |clock| clock.unix_timestamp >= expires_at)
+        })
+    }
+
+    /// Roles that still count; stale or expired records hold none
+    pub fn active_roles(&self, member_epoch: u32) -> u8 {
+        if self.is_active(member_epoch) { self.roles } else { 0 }
+    }
+
+    pub fn has_role(&self, role: u8, member_epoch: u32) -> bool {
+        self.is_active(member_epoch) && self.roles & role != 0
+    }
+
+    pub fn is_owner(&self, member_epoch: u32) -> bool {
+        self.has_role(ROLE_OWNER, member_epoch)
+    }
+
+    pub fn is_admin(&self, member_epoch: u32) -> bool {
+        self.has_role(ROLE_ADMIN, member_epoch)
+    }
+
+    /// Admins and creators may both author content
+    pub fn can_create_content(&self, member_epoch: u32) -> bool {
+        self.has_role(ROLE_ADMIN | ROLE_CREATOR, member_epoch)
+    }
+}
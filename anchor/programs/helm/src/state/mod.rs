@@ -1,11 +1,19 @@
 // /src/state/mod.rs
 
 pub mod twitter_account;
-pub mod admin_list;
+pub mod approval_policy;
 pub mod content;
-pub mod creator_list;
+pub mod content_revision;
+pub mod member;
+pub mod invite;
+pub mod attestation_config;
+pub mod handle_index;
 
 pub use twitter_account::*;
-pub use admin_list::*;
+pub use approval_policy::*;
 pub use content::*;
-pub use creator_list::*;
+pub use content_revision::*;
+pub use member::*;
+pub use invite::*;
+pub use attestation_config::*;
+pub use handle_index::*;
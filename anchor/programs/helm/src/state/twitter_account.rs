@@ -1,20 +1,70 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{ DEFAULT_REQUIRED_APPROVALS, MAX_REQUIRED_APPROVALS },
+    constants::{
+        DEFAULT_MAX_PUBLISH_ATTEMPTS,
+        DEFAULT_REQUIRED_APPROVALS,
+        MAX_EXPIRING_ADMINS,
+        MAX_EXPIRY_CRANK_FEE,
+        MAX_HANDLE_HISTORY,
+        MAX_OWNERSHIP_PROPOSAL_WINDOW,
+        MAX_PUBLISH_ATTEMPTS,
+        MIN_PUBLISH_ATTEMPTS,
+    },
+    enums::ReschedulePolicy,
     errors::HelmError,
+    state::ApprovalPolicy,
 };
 
+/// A handle the account used before, kept so older content stays attributable
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct HandleChange {
+    pub handle: String,
+    /// When the account stopped using this handle
+    pub changed_at: i64,
+}
+
 #[account]
 pub struct TwitterAccount {
     /// The wallet that initialized this Twitter account integration
     pub owner: Pubkey,
+    /// Wallet proposed to take over ownership, if any
+    pub pending_owner: Option<Pubkey>,
+    /// When the pending ownership proposal lapses
+    pub pending_owner_expires_at: Option<i64>,
     /// Twitter account identifier (off-chain)
     pub twitter_id: String,
     /// Twitter handle (off-chain verification)
     pub twitter_handle: String,
-    /// Required number of approvals for this account
-    pub required_approvals: u8,
+    /// Previous handles, oldest first
+    pub handle_history: Vec<HandleChange>,
+    /// Policy deciding when content has enough approvals
+    pub approval_policy: ApprovalPolicy,
+    /// How many times the service may try to publish content before giving up
+    pub max_publish_attempts: u8,
+    /// What rescheduling does to approvals already given
+    pub reschedule_policy: ReschedulePolicy,
+    /// Lamports paid to whoever expires stale content; zero keeps expired content around
+    pub expiry_crank_fee: u64,
+    /// Number of content accounts created, used as the next content sequence
+    pub content_count: u64,
+    /// Content not yet published or canceled; must be zero to deregister
+    pub open_content_count: u32,
+    /// Content accounts not yet closed, whatever their status
+    pub content_record_count: u32,
+    /// Archived content revisions not yet closed
+    pub revision_record_count: u32,
+    /// Member records not yet closed, the owner's included
+    pub member_count: u32,
+    /// Invites not yet accepted or revoked
+    pub invite_count: u32,
+    /// Number of active members holding the Admin role
+    pub admin_count: u32,
+    /// Expiry of every counted admin whose membership lapses, so lapsed admins
+    /// stop counting toward approval policies before they are pruned
+    pub admin_expiries: Vec<i64>,
+    /// Bumped on reclaim so every earlier Member record stops counting
+    pub member_epoch: u32,
     /// Whether the Twitter account is verified with the service
     pub is_verified: bool,
     /// When this integration was created
@@ -33,20 +83,221 @@ impl TwitterAccount {
         bump: u8
     ) {
         self.owner = owner;
+        self.pending_owner = None;
+        self.pending_owner_expires_at = None;
         self.twitter_id = twitter_id;
         self.twitter_handle = twitter_handle;
-        self.required_approvals = DEFAULT_REQUIRED_APPROVALS;
+        self.handle_history = Vec::new();
+        self.approval_policy = ApprovalPolicy::Threshold { required: DEFAULT_REQUIRED_APPROVALS };
+        self.max_publish_attempts = DEFAULT_MAX_PUBLISH_ATTEMPTS;
+        self.reschedule_policy = ReschedulePolicy::RequireReapproval;
+        self.expiry_crank_fee = 0;
+        self.content_count = 0;
+        self.open_content_count = 0;
+        self.content_record_count = 0;
+        self.revision_record_count = 0;
+        self.member_count = 1;
+        self.invite_count = 0;
+        self.admin_count = 1;
+        self.admin_expiries = Vec::new();
+        self.member_epoch = 0;
         self.is_verified = false;
         self.created_at = timestamp;
         self.bump = bump;
     }
 
-    pub fn update_required_approvals(&mut self, new_value: u8) -> Result<()> {
+    /// Hands the account to a new owner with a fresh configuration, verified because the
+    /// reclaim attestation vouches for the handle too; the new owner starts out as the only admin
+    pub fn reassign_owner(&mut self, new_owner: Pubkey) {
+        self.member_epoch = self.member_epoch.wrapping_add(1);
+        self.admin_count = 1;
+        self.admin_expiries.clear();
+        self.owner = new_owner;
+        self.pending_owner = None;
+        self.pending_owner_expires_at = None;
+        self.approval_policy = ApprovalPolicy::Threshold { required: DEFAULT_REQUIRED_APPROVALS };
+        self.is_verified = true;
+    }
+
+    pub fn propose_owner(&mut self, new_owner: Pubkey, expires_at: i64, timestamp: i64) -> Result<()> {
+        require!(new_owner != self.owner, HelmError::InvalidOwnershipProposal);
+        require!(expires_at > timestamp, HelmError::InvalidOwnershipProposal);
+        require!(
+            expires_at <= timestamp.saturating_add(MAX_OWNERSHIP_PROPOSAL_WINDOW),
+            HelmError::InvalidOwnershipProposal
+        );
+
+        self.pending_owner = Some(new_owner);
+        self.pending_owner_expires_at = Some(expires_at);
+        Ok(())
+    }
+
+    pub fn accept_ownership(&mut self, new_owner: Pubkey, timestamp: i64) -> Result<()> {
+        require!(self.pending_owner == Some(new_owner), HelmError::NoPendingOwnershipTransfer);
+        if let Some(expires_at) = self.pending_owner_expires_at {
+            require!(timestamp < expires_at, HelmError::OwnershipProposalExpired);
+        }
+
+        // Keep group policies pointing at the owner's admin slot
+        let previous_owner = self.owner;
+        self.approval_policy.replace_member(&previous_owner, new_owner);
+
+        self.owner = new_owner;
+        self.pending_owner = None;
+        self.pending_owner_expires_at = None;
+        Ok(())
+    }
+
+    pub fn change_handle(&mut self, new_handle: String, timestamp: i64) -> Result<()> {
+        require!(new_handle != self.twitter_handle, HelmError::InvalidTwitterHandle);
+
+        // Drop the oldest entry once the history is full
+        if self.handle_history.len() >= MAX_HANDLE_HISTORY {
+            self.handle_history.remove(0);
+        }
+
+        let previous_handle = std::mem::replace(&mut self.twitter_handle, new_handle);
+        self.handle_history.push(HandleChange {
+            handle: previous_handle,
+            changed_at: timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn next_content_sequence(&mut self) -> Result<u64> {
+        let sequence = self.content_count;
+        self.content_count = sequence.checked_add(1).ok_or(HelmError::ContentSequenceOverflow)?;
+        self.open_content_count = self.open_content_count
+            .checked_add(1)
+            .ok_or(HelmError::ContentSequenceOverflow)?;
+        self.content_record_count = self.content_record_count
+            .checked_add(1)
+            .ok_or(HelmError::ContentSequenceOverflow)?;
+        Ok(sequence)
+    }
+
+    /// Called once content reaches a terminal status
+    pub fn close_content(&mut self) {
+        self.open_content_count = self.open_content_count.saturating_sub(1);
+    }
+
+    // Member, Invite, Content and ContentRevision PDAs are tallied so deregistering cannot leave any
+    // behind for a later registration under the same Twitter ID to revive
+
+    pub fn content_record_closed(&mut self) {
+        self.content_record_count = self.content_record_count.saturating_sub(1);
+    }
+
+    pub fn revision_record_created(&mut self) {
+        self.revision_record_count = self.revision_record_count.saturating_add(1);
+    }
+
+    pub fn revision_record_closed(&mut self) {
+        self.revision_record_count = self.revision_record_count.saturating_sub(1);
+    }
+
+    pub fn member_record_created(&mut self) {
+        self.member_count = self.member_count.saturating_add(1);
+    }
+
+    pub fn member_record_closed(&mut self) {
+        self.member_count = self.member_count.saturating_sub(1);
+    }
+
+    pub fn invite_record_created(&mut self) {
+        self.invite_count = self.invite_count.saturating_add(1);
+    }
+
+    pub fn invite_record_closed(&mut self) {
+        self.invite_count = self.invite_count.saturating_sub(1);
+    }
+
+    /// Only the owner's own membership may be left when deregistering
+    pub fn has_dependent_records(&self) -> bool {
+        self.member_count > 1 ||
+            self.invite_count > 0 ||
+            self.content_record_count > 0 ||
+            self.revision_record_count > 0
+    }
+
+    /// Counts one more admin, refusing if a percentage policy would then need more
+    /// approvals than content can hold. This is not a cap on admins: threshold and group
+    /// policies never hit it, and past that point a percentage policy could approve
+    /// nothing, so the owner lowers the percentage or switches policies first.
+    pub fn add_admin(&mut self) -> Result<()> {
+        self.admin_count = self.admin_count.checked_add(1).ok_or(HelmError::MaxAdminsReached)?;
+        require!(
+            self.approval_policy.within_approval_capacity(self.admin_count),
+            HelmError::TooManyRequiredApprovals
+        );
+        Ok(())
+    }
+
+    /// Takes `wallet` out of the admin count and approval groups, refusing if that would
+    /// put a reachable approval policy out of reach; the owner must change the policy first
+    pub fn remove_admin(&mut self, wallet: &Pubkey, expires_at: Option<i64>) -> Result<()> {
+        let remaining_admins = self.admin_count
+            .checked_sub(1)
+            .ok_or(HelmError::AdminDoesNotExist)?;
+        let was_reachable = self.approval_policy.is_reachable(self.admin_count);
+        self.approval_policy.remove_member(wallet);
+        require!(
+            !was_reachable || self.approval_policy.is_reachable(remaining_admins),
+            HelmError::RequiredApprovalsUnreachable
+        );
+        self.admin_count = remaining_admins;
+        self.untrack_admin_expiry(expires_at);
+        Ok(())
+    }
+
+    /// Records the expiry of an admin's membership, if it has one
+    pub fn track_admin_expiry(&mut self, expires_at: Option<i64>) -> Result<()> {
+        if let Some(expires_at) = expires_at {
+            require!(
+                self.admin_expiries.len() < MAX_EXPIRING_ADMINS,
+                HelmError::TooManyExpiringAdmins
+            );
+            self.admin_expiries.push(expires_at);
+        }
+        Ok(())
+    }
+
+    /// Forgets the expiry of an admin leaving the admin count or changing its expiry
+    pub fn untrack_admin_expiry(&mut self, expires_at: Option<i64>) {
+        if let Some(expires_at) = expires_at {
+            if let Some(index) = self.admin_expiries.iter().position(|e| *e == expires_at) {
+                self.admin_expiries.swap_remove(index);
+            }
+        }
+    }
+
+    /// Admins whose membership has not lapsed by `timestamp`; approval policies count these
+    pub fn active_admin_count(&self, timestamp: i64) -> u32 {
+        let expired = self.admin_expiries
+            .iter()
+            .filter(|expires_at| timestamp >= **expires_at)
+            .count() as u32;
+        self.admin_count.saturating_sub(expired)
+    }
+
+    pub fn update_approval_policy(&mut self, policy: ApprovalPolicy, admins: &[Pubkey]) -> Result<()> {
+        policy.validate(self.admin_count, admins)?;
+        self.approval_policy = policy;
+        Ok(())
+    }
+
+    pub fn update_expiry_crank_fee(&mut self, fee: u64) -> Result<()> {
+        require!(fee <= MAX_EXPIRY_CRANK_FEE, HelmError::InvalidExpiryCrankFee);
+        self.expiry_crank_fee = fee;
+        Ok(())
+    }
+
+    pub fn update_max_publish_attempts(&mut self, new_value: u8) -> Result<()> {
         require!(
-            new_value > 0 && new_value <= MAX_REQUIRED_APPROVALS,
-            HelmError::InvalidRequiredApprovals
+            (MIN_PUBLISH_ATTEMPTS..=MAX_PUBLISH_ATTEMPTS).contains(&new_value),
+            HelmError::InvalidMaxPublishAttempts
         );
-        self.required_approvals = new_value;
+        self.max_publish_attempts = new_value;
         Ok(())
     }
 }
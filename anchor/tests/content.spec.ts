@@ -3,24 +3,35 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Helm } from "../target/types/helm";
-import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import {
+  PublicKey,
+  SystemProgram,
+  Keypair,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 // import { expect } from "chai";
-import { setupVerifiedAccount } from "./helm.spec";
+import {
+  createReclaimAttestation,
+  findHandleIndexPDA,
+  findInvitePDA,
+  findMemberPDA,
+  loadServiceAuthority,
+  ROLE_ADMIN,
+  setupVerifiedAccount,
+} from "./helm.spec";
 import { keccak_256 } from "js-sha3";
 import { describe, it, expect, jest } from "@jest/globals";
 
 const findContentPDA = (
   twitterAccount: PublicKey,
-  author: PublicKey,
-  contentHash: Buffer,
+  sequence: number,
   programId: PublicKey
 ) => {
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from("content"),
       twitterAccount.toBuffer(),
-      author.toBuffer(),
-      contentHash,
+      new anchor.BN(sequence).toArrayLike(Buffer, "le", 8),
     ],
     programId
   );
@@ -30,6 +41,81 @@ const createContentHash = (content: string): Buffer => {
   return Buffer.from(keccak_256(content), "hex");
 };
 
+// Mirrors src/merkle.rs: prefixed leaves and nodes, unpaired nodes carried up
+const hashParts = (...parts: Buffer[]): Buffer =>
+  Buffer.from(keccak_256(Buffer.concat(parts)), "hex");
+
+const buildThread = (tweets: string[]) => {
+  const tweetHashes = tweets.map(createContentHash);
+  const levels = [tweetHashes.map((hash) => hashParts(Buffer.from([0]), hash))];
+  while (levels[levels.length - 1].length > 1) {
+    const level = levels[levels.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      next.push(
+        i + 1 < level.length
+          ? hashParts(Buffer.from([1]), level[i], level[i + 1])
+          : level[i]
+      );
+    }
+    levels.push(next);
+  }
+
+  const proof = (index: number) => {
+    const siblings: number[][] = [];
+    for (const level of levels.slice(0, -1)) {
+      const sibling = index ^ 1;
+      if (sibling < level.length) siblings.push(Array.from(level[sibling]));
+      index = Math.floor(index / 2);
+    }
+    return siblings;
+  };
+
+  return {
+    tweetHashes,
+    contentType: {
+      thread: {
+        tweetCount: tweets.length,
+        tweetsRoot: Array.from(levels[levels.length - 1][0]),
+      },
+    },
+    proof,
+  };
+};
+
+// Approvers' Member PDAs, so the program can check they are still admins
+const approverAccounts = (
+  twitterAccount: PublicKey,
+  approvers: PublicKey[],
+  programId: PublicKey
+) =>
+  approvers.map((approver) => ({
+    pubkey: findMemberPDA(twitterAccount, approver, programId)[0],
+    isSigner: false,
+    isWritable: false,
+  }));
+
+// CPI events are the inner instructions of a transaction, minus the 8-byte event tag
+const fetchCpiEvents = async (
+  program: Program<Helm>,
+  signature: string
+) => {
+  const tx = await program.provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+
+  return (tx?.meta?.innerInstructions ?? [])
+    .flatMap(({ instructions }) => instructions)
+    .map((ix) => {
+      const data = anchor.utils.bytes.bs58.decode(ix.data);
+      return program.coder.events.decode(
+        anchor.utils.bytes.base64.encode(Buffer.from(data.subarray(8)))
+      );
+    })
+    .filter((event) => event !== null);
+};
+
 describe("content workflow", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -39,16 +125,12 @@ describe("content workflow", () => {
   // File: tests/content.ts
 
   it("Can submit content for approval", async () => {
-    const { twitterAccountPda, adminListPda } = await setupVerifiedAccount(
-      program,
-      provider
-    );
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
     const contentHash = createContentHash("Test tweet content");
 
     const [contentPda] = findContentPDA(
       twitterAccountPda,
-      provider.wallet.publicKey,
-      contentHash,
+      0,
       program.programId
     );
 
@@ -61,7 +143,6 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
@@ -90,10 +171,7 @@ describe("content workflow", () => {
   // File: tests/content.ts
 
   it("Can approve content", async () => {
-    const { twitterAccountPda, adminListPda } = await setupVerifiedAccount(
-      program,
-      provider
-    );
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
 
     // Create two new admins to reach required threshold of 3
     const newAdmin1 = Keypair.generate();
@@ -113,19 +191,18 @@ describe("content workflow", () => {
     // Add both new admins
     for (const admin of [newAdmin1, newAdmin2]) {
       await program.methods
-        .addAdmin(admin.publicKey)
+        .addAdmin(admin.publicKey, null)
         .accountsPartial({
-          adminList: adminListPda,
           twitterAccount: twitterAccountPda,
           owner: provider.wallet.publicKey,
+          memberPayer: null,
         })
         .rpc();
     }
 
     const [contentPda] = findContentPDA(
       twitterAccountPda,
-      provider.wallet.publicKey,
-      contentHash,
+      0,
       program.programId
     );
 
@@ -135,7 +212,6 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
@@ -146,7 +222,6 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: newAdmin1.publicKey,
       })
       .instruction();
@@ -165,9 +240,15 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: newAdmin2.publicKey,
       })
+      .remainingAccounts(
+        approverAccounts(
+          twitterAccountPda,
+          [provider.wallet.publicKey, newAdmin1.publicKey],
+          program.programId
+        )
+      )
       .instruction();
 
     tx = new anchor.web3.Transaction().add(approve2Ix);
@@ -184,16 +265,12 @@ describe("content workflow", () => {
   });
 
   it("Can reject content with reason", async () => {
-    const { twitterAccountPda, adminListPda } = await setupVerifiedAccount(
-      program,
-      provider
-    );
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
     const contentHash = createContentHash("Test tweet content");
 
     const [contentPda] = findContentPDA(
       twitterAccountPda,
-      provider.wallet.publicKey,
-      contentHash,
+      0,
       program.programId
     );
 
@@ -203,11 +280,21 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
 
+    await expect(
+      program.methods
+        .rejectContent("x".repeat(257))
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow("Reason too long");
+
     // Reject the content
     const rejectionReason = "Content does not meet guidelines";
     await program.methods
@@ -215,27 +302,39 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
 
-    const contentAccount = await program.account.content.fetch(contentPda);
+    let contentAccount = await program.account.content.fetch(contentPda);
     expect(Object.keys(contentAccount.status)[0]).toBe("rejected");
     expect(contentAccount.rejectionReason).toBe(rejectionReason);
+
+    // Rejected content no author will revise can still be canceled by an admin
+    await program.methods
+      .cancelContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("canceled");
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.openContentCount).toBe(0);
   });
 
   it("Can cancel content", async () => {
-    const { twitterAccountPda, adminListPda } = await setupVerifiedAccount(
-      program,
-      provider
-    );
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
     const contentHash = createContentHash("Test tweet content");
 
     const [contentPda] = findContentPDA(
       twitterAccountPda,
-      provider.wallet.publicKey,
-      contentHash,
+      0,
       program.programId
     );
 
@@ -245,7 +344,6 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
@@ -256,7 +354,6 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
@@ -266,16 +363,12 @@ describe("content workflow", () => {
   });
 
   it("Validates scheduled content timing", async () => {
-    const { twitterAccountPda, adminListPda } = await setupVerifiedAccount(
-      program,
-      provider
-    );
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
     const contentHash = createContentHash("Test tweet content");
 
     const [contentPda] = findContentPDA(
       twitterAccountPda,
-      provider.wallet.publicKey,
-      contentHash,
+      0,
       program.programId
     );
 
@@ -292,7 +385,6 @@ describe("content workflow", () => {
         .accountsPartial({
           content: contentPda,
           twitterAccount: twitterAccountPda,
-          adminList: adminListPda,
           authority: provider.wallet.publicKey,
         })
         .rpc()
@@ -300,16 +392,12 @@ describe("content workflow", () => {
   });
 
   it("Enforces valid state transitions", async () => {
-    const { twitterAccountPda, adminListPda } = await setupVerifiedAccount(
-      program,
-      provider
-    );
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
     const contentHash = createContentHash("Test tweet content");
 
     const [contentPda] = findContentPDA(
       twitterAccountPda,
-      provider.wallet.publicKey,
-      contentHash,
+      0,
       program.programId
     );
 
@@ -319,7 +407,6 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
@@ -330,7 +417,6 @@ describe("content workflow", () => {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
         authority: provider.wallet.publicKey,
       })
       .rpc();
@@ -342,10 +428,1693 @@ describe("content workflow", () => {
         .accountsPartial({
           content: contentPda,
           twitterAccount: twitterAccountPda,
-          adminList: adminListPda,
           authority: provider.wallet.publicKey,
         })
         .rpc()
     ).rejects.toThrow();
   });
+
+  it("Only the service authority can mark content published", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const contentHash = createContentHash("Test tweet content");
+
+    const [contentPda] = findContentPDA(
+      twitterAccountPda,
+      0,
+      program.programId
+    );
+
+    await program.methods
+      .submitForApproval({ tweet: {} }, Array.from(contentHash), null)
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    // The account owner is not the service authority
+    await expect(
+      program.methods
+        .markPublished([new anchor.BN(1)])
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow("Not authorized");
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(contentAccount.publishedTweetIds).toHaveLength(0);
+    expect(contentAccount.publishedAt).toBeNull();
+  });
+
+  it("Service authority can mark approved content published", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const serviceAuthority = loadServiceAuthority();
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+
+    await program.methods
+      .updateRequiredApprovals(1)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Ready to publish")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    let twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.openContentCount).toBe(1);
+
+    // A single tweet needs exactly one tweet ID
+    await expect(
+      program.methods
+        .markPublished([new anchor.BN(1), new anchor.BN(2)])
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: serviceAuthority.publicKey,
+        })
+        .signers([serviceAuthority])
+        .rpc()
+    ).rejects.toThrow("Published tweet IDs do not match content type");
+
+    await program.methods
+      .markPublished([new anchor.BN(1234567890)])
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: serviceAuthority.publicKey,
+      })
+      .remainingAccounts(
+        approverAccounts(
+          twitterAccountPda,
+          [provider.wallet.publicKey],
+          program.programId
+        )
+      )
+      .signers([serviceAuthority])
+      .rpc();
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("published");
+    expect(contentAccount.publishedTweetIds.map((id) => id.toNumber())).toEqual(
+      [1234567890]
+    );
+    expect(contentAccount.publishedAt).not.toBeNull();
+
+    twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.openContentCount).toBe(0);
+  });
+
+  it("Sends content approved before a reclaim back for review", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const { twitterId, twitterHandle } =
+      await program.account.twitterAccount.fetch(twitterAccountPda);
+    const serviceAuthority = loadServiceAuthority();
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+
+    await program.methods
+      .updateRequiredApprovals(1)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Approved by the old admins")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+
+    const claimant = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      claimant.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+    const expiresAt = Math.floor(Date.now() / 1000) + 600;
+    await program.methods
+      .reclaimTwitterAccount(new anchor.BN(expiresAt))
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        handleIndex: findHandleIndexPDA(twitterHandle, program.programId)[0],
+        attestationConfig: null,
+        claimant: claimant.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([
+        createReclaimAttestation(
+          twitterAccountPda,
+          twitterId,
+          claimant.publicKey,
+          0,
+          expiresAt,
+          twitterHandle,
+          serviceAuthority
+        ),
+      ])
+      .signers([claimant])
+      .rpc();
+
+    // The approvals predate the reclaim, so nothing is published
+    const serviceAccounts = {
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority: serviceAuthority.publicKey,
+    };
+    await expect(
+      program.methods
+        .markPublished([new anchor.BN(1234567890)])
+        .accountsPartial(serviceAccounts)
+        .signers([serviceAuthority])
+        .rpc()
+    ).rejects.toThrow("Content approvals are no longer in force");
+
+    // ...and the service sends it back for review instead
+    await program.methods
+      .requeueContent()
+      .accountsPartial(serviceAccounts)
+      .signers([serviceAuthority])
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals).toHaveLength(0);
+    expect(contentAccount.approvalsEpoch).toBe(1);
+    expect(contentAccount.publishedTweetIds).toHaveLength(0);
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.openContentCount).toBe(1);
+  });
+
+  it("Records failed publication attempts and gates retries", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const serviceAuthority = loadServiceAuthority();
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const [ownerMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      provider.wallet.publicKey,
+      program.programId
+    );
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const serviceAccounts = {
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority: serviceAuthority.publicKey,
+    };
+    const adminRetryAccounts = {
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      member: ownerMemberPda,
+      authority: provider.wallet.publicKey,
+    };
+    const now = async () =>
+      provider.connection.getBlockTime(await provider.connection.getSlot());
+
+    await program.methods
+      .updateRequiredApprovals(1)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+    await program.methods
+      .updateMaxPublishAttempts(2)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Flaky tweet")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await expect(
+      program.methods
+        .markFailed("x".repeat(257), null)
+        .accountsPartial(serviceAccounts)
+        .signers([serviceAuthority])
+        .rpc()
+    ).rejects.toThrow("Reason too long");
+
+    // First attempt fails with a retry far in the future
+    await program.methods
+      .markFailed("Rate limited", new anchor.BN((await now()) + 3600))
+      .accountsPartial(serviceAccounts)
+      .signers([serviceAuthority])
+      .rpc();
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("failed");
+    expect(contentAccount.failureReason).toBe("Rate limited");
+    expect(contentAccount.publishAttempts).toBe(1);
+    expect(contentAccount.nextRetryAt).not.toBeNull();
+
+    await expect(
+      program.methods
+        .retryContent(false)
+        .accountsPartial({ ...serviceAccounts, member: null })
+        .signers([serviceAuthority])
+        .rpc()
+    ).rejects.toThrow("Retry not ready yet");
+
+    // Only admins may send content back through review
+    await expect(
+      program.methods
+        .retryContent(true)
+        .accountsPartial({ ...serviceAccounts, member: null })
+        .signers([serviceAuthority])
+        .rpc()
+    ).rejects.toThrow("Not authorized");
+
+    await program.methods
+      .retryContent(true)
+      .accountsPartial(adminRetryAccounts)
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals).toHaveLength(0);
+    expect(contentAccount.publishAttempts).toBe(0);
+    expect(contentAccount.failureReason).toBeNull();
+
+    await program.methods
+      .approveContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    // Use up the attempt budget, retrying once the short delay has passed
+    const retryAt = (await now()) + 2;
+    await program.methods
+      .markFailed("Timeout", new anchor.BN(retryAt))
+      .accountsPartial(serviceAccounts)
+      .signers([serviceAuthority])
+      .rpc();
+    while ((await now()) < retryAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+    await program.methods
+      .retryContent(false)
+      .accountsPartial({ ...serviceAccounts, member: null })
+      .signers([serviceAuthority])
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+
+    await program.methods
+      .markFailed("Timeout again", null)
+      .accountsPartial(serviceAccounts)
+      .signers([serviceAuthority])
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(contentAccount.publishAttempts).toBe(2);
+    expect(contentAccount.nextRetryAt).toBeNull();
+
+    await expect(
+      program.methods
+        .retryContent(false)
+        .accountsPartial(adminRetryAccounts)
+        .rpc()
+    ).rejects.toThrow("Maximum publish attempts reached");
+  });
+
+  it("Creators can submit content without approving it", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const creator = Keypair.generate();
+
+    const signature = await provider.connection.requestAirdrop(
+      creator.publicKey,
+      2 * anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+
+    await program.methods
+      .addCreator(creator.publicKey, null)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+        memberPayer: null,
+      })
+      .rpc();
+
+    const contentHash = createContentHash("Creator tweet content");
+    const [contentPda] = findContentPDA(
+      twitterAccountPda,
+      0,
+      program.programId
+    );
+
+    await program.methods
+      .submitForApproval({ tweet: {} }, Array.from(contentHash), null)
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: creator.publicKey,
+      })
+      .signers([creator])
+      .rpc();
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(contentAccount.author.toString()).toBe(creator.publicKey.toString());
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals).toHaveLength(0);
+  });
+
+  it("Can create, update and submit a draft", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+
+    await program.methods
+      .createDraft(
+        { tweet: {} },
+        Array.from(createContentHash("First draft")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("draft");
+    expect(contentAccount.sequence.toNumber()).toBe(0);
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.contentCount.toNumber()).toBe(1);
+
+    const revisedHash = createContentHash("Second draft");
+    const thread = buildThread(["Second draft 1/2", "Second draft 2/2"]);
+    await program.methods
+      .updateDraft(thread.contentType, Array.from(revisedHash), null)
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(contentAccount.contentHash).toEqual(Array.from(revisedHash));
+    expect(contentAccount.contentType).toEqual(thread.contentType);
+
+    await program.methods
+      .submitDraft()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals).toHaveLength(1);
+
+    // Submitted content can no longer be edited as a draft
+    await expect(
+      program.methods
+        .updateDraft({ tweet: {} }, Array.from(revisedHash), null)
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow("Invalid content status for operation");
+  });
+
+  it("Can revise rejected content and keep its history", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const contentHash = createContentHash("Test tweet content");
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const accounts = {
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority: provider.wallet.publicKey,
+    };
+
+    await program.methods
+      .submitForApproval({ tweet: {} }, Array.from(contentHash), null)
+      .accountsPartial(accounts)
+      .rpc();
+
+    const rejectionReason = "Fix the typo";
+    await program.methods
+      .rejectContent(rejectionReason)
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const [revisionPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("content-revision"),
+        contentPda.toBuffer(),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 2),
+      ],
+      program.programId
+    );
+
+    const revisedHash = createContentHash("Test tweet content, fixed");
+    await program.methods
+      .reviseContent(Array.from(revisedHash))
+      .accountsPartial({ ...accounts, contentRevision: revisionPda })
+      .rpc();
+
+    const revision = await program.account.contentRevision.fetch(revisionPda);
+    expect(revision.revision).toBe(0);
+    expect(revision.contentHash).toEqual(Array.from(contentHash));
+    expect(revision.rejectionReason).toBe(rejectionReason);
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("draft");
+    expect(contentAccount.revision).toBe(1);
+    expect(contentAccount.contentHash).toEqual(Array.from(revisedHash));
+    expect(contentAccount.approvals).toHaveLength(0);
+    expect(contentAccount.rejectionReason).toBeNull();
+
+    await program.methods.submitDraft().accountsPartial(accounts).rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+
+    // History stays while the content is in review
+    const closeRevisionAccounts = {
+      contentRevision: revisionPda,
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      revisedBy: provider.wallet.publicKey,
+      caller: provider.wallet.publicKey,
+    };
+    await expect(
+      program.methods
+        .closeContentRevision()
+        .accountsPartial(closeRevisionAccounts)
+        .rpc()
+    ).rejects.toThrow(
+      "Content must be published, canceled or expired before it can be closed"
+    );
+
+    // Once the content is gone its revisions are returned to their author
+    await program.methods.cancelContent().accountsPartial(accounts).rpc();
+    await program.methods
+      .closeContent()
+      .accountsPartial({
+        content: contentPda,
+        payer: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        twitterAccount: twitterAccountPda,
+        member: null,
+      })
+      .rpc();
+
+    let twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.revisionRecordCount).toBe(1);
+
+    await program.methods
+      .closeContentRevision()
+      .accountsPartial(closeRevisionAccounts)
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(revisionPda)).toBeNull();
+    twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.revisionRecordCount).toBe(0);
+  });
+
+  it("Can revoke an approval before publication", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const contentHash = createContentHash("Test tweet content");
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+
+    await program.methods
+      .submitForApproval({ tweet: {} }, Array.from(contentHash), null)
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const revokeAccounts = {
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority: provider.wallet.publicKey,
+    };
+
+    await program.methods.revokeApproval().accountsPartial(revokeAccounts).rpc();
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals).toHaveLength(0);
+
+    await expect(
+      program.methods.revokeApproval().accountsPartial(revokeAccounts).rpc()
+    ).rejects.toThrow("Content not approved by this admin");
+  });
+
+  it("Evaluates a percentage approval policy", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+
+    // Half of the single admin rounds up to one approval
+    await program.methods
+      .updateApprovalPolicy({ percentage: { percent: 50 } })
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const contentHash = createContentHash("Test tweet content");
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+
+    await program.methods
+      .submitForApproval({ tweet: {} }, Array.from(contentHash), null)
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+  });
+
+  it("Keeps percentage policies within the approvals content can hold", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const addAdmin = () =>
+      program.methods
+        .addAdmin(Keypair.generate().publicKey, null)
+        .accountsPartial({ ...ownerAccounts, memberPayer: null })
+        .rpc();
+
+    // 21 admins in all, one more than content can hold approvals for
+    for (let i = 0; i < 20; i++) {
+      await addAdmin();
+    }
+
+    await expect(
+      program.methods
+        .updateApprovalPolicy({ percentage: { percent: 100 } })
+        .accountsPartial(ownerAccounts)
+        .rpc()
+    ).rejects.toThrow("Required approvals exceed what content can hold");
+
+    // 95% of 21 rounds up to exactly 20
+    await program.methods
+      .updateApprovalPolicy({ percentage: { percent: 95 } })
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    await expect(addAdmin()).rejects.toThrow(
+      "Required approvals exceed what content can hold"
+    );
+  });
+
+  it("Stops counting expired admins before they are pruned", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const now = async () =>
+      provider.connection.getBlockTime(await provider.connection.getSlot());
+    const expiresAt = (await now()) + 2;
+    const submit = async (sequence: number) => {
+      const [contentPda] = findContentPDA(
+        twitterAccountPda,
+        sequence,
+        program.programId
+      );
+      await program.methods
+        .submitForApproval(
+          { tweet: {} },
+          Array.from(createContentHash(`Needs every admin ${sequence}`)),
+          null
+        )
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+      const content = await program.account.content.fetch(contentPda);
+      return Object.keys(content.status)[0];
+    };
+
+    await program.methods
+      .addAdmin(Keypair.generate().publicKey, new anchor.BN(expiresAt))
+      .accountsPartial({ ...ownerAccounts, memberPayer: null })
+      .rpc();
+    await program.methods
+      .updateApprovalPolicy({ percentage: { percent: 100 } })
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    // Both admins must approve while the second one's membership lasts
+    expect(await submit(0)).toBe("pendingApproval");
+
+    while ((await now()) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    // The lapsed admin is still on the books but no longer counts
+    expect(await submit(1)).toBe("approved");
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(2);
+    expect(twitterAccount.adminExpiries.map((e) => e.toNumber())).toEqual([
+      expiresAt,
+    ]);
+  });
+
+  it("Rejects group policies naming non-admins", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [ownerMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      provider.wallet.publicKey,
+      program.programId
+    );
+
+    // Only the owner's membership backs the group members
+    await expect(
+      program.methods
+        .updateApprovalPolicy({
+          groups: {
+            groups: [
+              { name: "legal", members: [provider.wallet.publicKey] },
+              { name: "marketing", members: [Keypair.generate().publicKey] },
+            ],
+          },
+        })
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          owner: provider.wallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: ownerMemberPda, isSigner: false, isWritable: false },
+        ])
+        .rpc()
+    ).rejects.toThrow("Admin does not exist");
+  });
+
+  it("Strips a removed admin's approvals from open content", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const [secondAdmin, thirdAdmin] = [Keypair.generate(), Keypair.generate()];
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+
+    for (const admin of [secondAdmin, thirdAdmin]) {
+      await program.methods
+        .addAdmin(admin.publicKey, null)
+        .accountsPartial({ ...ownerAccounts, memberPayer: null })
+        .rpc();
+    }
+    await program.methods
+      .updateRequiredApprovals(2)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Needs two approvals")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods
+      .approveContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: secondAdmin.publicKey,
+      })
+      .remainingAccounts(
+        approverAccounts(
+          twitterAccountPda,
+          [provider.wallet.publicKey],
+          program.programId
+        )
+      )
+      .signers([secondAdmin])
+      .rpc();
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+
+    // The open content is passed along so the approval stops counting
+    await program.methods
+      .removeAdmin(secondAdmin.publicKey)
+      .accountsPartial({
+        ...ownerAccounts,
+        memberPayer: provider.wallet.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: contentPda, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals.map((a) => a.toString())).toEqual([
+      provider.wallet.publicKey.toString(),
+    ]);
+  });
+
+  it("Only counts approvals from current admins", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const serviceAuthority = loadServiceAuthority();
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const [secondAdmin, thirdAdmin] = [Keypair.generate(), Keypair.generate()];
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const contentAccounts = (authority: PublicKey) => ({
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority,
+    });
+    const approvers = approverAccounts(
+      twitterAccountPda,
+      [provider.wallet.publicKey, secondAdmin.publicKey, thirdAdmin.publicKey],
+      program.programId
+    );
+
+    for (const admin of [secondAdmin, thirdAdmin]) {
+      await program.methods
+        .addAdmin(admin.publicKey, null)
+        .accountsPartial({ ...ownerAccounts, memberPayer: null })
+        .rpc();
+    }
+    await program.methods
+      .updateRequiredApprovals(2)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Approved by a departing admin")),
+        null
+      )
+      .accountsPartial(contentAccounts(provider.wallet.publicKey))
+      .rpc();
+    await program.methods
+      .approveContent()
+      .accountsPartial(contentAccounts(secondAdmin.publicKey))
+      .remainingAccounts(approvers)
+      .signers([secondAdmin])
+      .rpc();
+
+    // The content is left out of the removal, so the approval stays on record
+    await program.methods
+      .removeAdmin(secondAdmin.publicKey)
+      .accountsPartial({
+        ...ownerAccounts,
+        memberPayer: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+    expect(contentAccount.approvals).toHaveLength(2);
+
+    // ...but it no longer counts once the service goes to publish
+    const publish = () =>
+      program.methods
+        .markPublished([new anchor.BN(1234567890)])
+        .accountsPartial(contentAccounts(serviceAuthority.publicKey))
+        .remainingAccounts(approvers)
+        .signers([serviceAuthority])
+        .rpc();
+    await expect(publish()).rejects.toThrow(
+      "Content approvals are no longer in force"
+    );
+
+    const requeue = () =>
+      program.methods
+        .requeueContent()
+        .accountsPartial(contentAccounts(serviceAuthority.publicKey))
+        .remainingAccounts(approvers)
+        .signers([serviceAuthority])
+        .rpc();
+    await requeue();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+
+    await program.methods
+      .approveContent()
+      .accountsPartial(contentAccounts(thirdAdmin.publicKey))
+      .remainingAccounts(approvers)
+      .signers([thirdAdmin])
+      .rpc();
+
+    // Content whose approvals hold is published, never requeued
+    await expect(requeue()).rejects.toThrow(
+      "Invalid content status for operation"
+    );
+    await publish();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("published");
+  });
+
+  it("Carries approvals over when an admin rotates keys", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const [oldKey, newKey] = [Keypair.generate(), Keypair.generate()];
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+
+    const signature = await provider.connection.requestAirdrop(
+      newKey.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+
+    await program.methods
+      .addAdmin(oldKey.publicKey, null)
+      .accountsPartial({ ...ownerAccounts, memberPayer: null })
+      .rpc();
+    await program.methods
+      .updateRequiredApprovals(2)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Approved before rotation")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods
+      .approveContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: oldKey.publicKey,
+      })
+      .remainingAccounts(
+        approverAccounts(
+          twitterAccountPda,
+          [provider.wallet.publicKey],
+          program.programId
+        )
+      )
+      .signers([oldKey])
+      .rpc();
+
+    // The owner's key only changes through an ownership transfer
+    await expect(
+      program.methods
+        .rotateAdminKey()
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          oldMemberPayer: provider.wallet.publicKey,
+          oldWallet: provider.wallet.publicKey,
+          newWallet: newKey.publicKey,
+          authority: provider.wallet.publicKey,
+        })
+        .signers([newKey])
+        .rpc()
+    ).rejects.toThrow("The owner's key changes through an ownership transfer");
+
+    await program.methods
+      .rotateAdminKey()
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        // The owner granted the old key, so its record's rent goes back to the owner
+        oldMemberPayer: provider.wallet.publicKey,
+        oldWallet: oldKey.publicKey,
+        newWallet: newKey.publicKey,
+        authority: oldKey.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: contentPda, isSigner: false, isWritable: true },
+      ])
+      .signers([oldKey, newKey])
+      .rpc();
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+    expect(contentAccount.approvals.map((a) => a.toString())).toEqual([
+      provider.wallet.publicKey.toString(),
+      newKey.publicKey.toString(),
+    ]);
+
+    const [oldMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      oldKey.publicKey,
+      program.programId
+    );
+    const [newMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      newKey.publicKey,
+      program.programId
+    );
+    expect(await provider.connection.getAccountInfo(oldMemberPda)).toBeNull();
+    const newMember = await program.account.member.fetch(newMemberPda);
+    expect(newMember.roles).toBe(ROLE_ADMIN);
+    // The new key paid for its record and gets the rent back when it closes
+    expect(newMember.payer.toString()).toBe(newKey.publicKey.toString());
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(2);
+  });
+
+  it("Can only deregister once every record is closed", async () => {
+    const { twitterAccountPda, twitterHandle } = await setupVerifiedAccount(
+      program,
+      provider
+    );
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const creator = Keypair.generate().publicKey;
+    const [creatorMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      creator,
+      program.programId
+    );
+    const deregisterAccounts = {
+      twitterAccount: twitterAccountPda,
+      handleIndex: findHandleIndexPDA(twitterHandle, program.programId)[0],
+      owner: provider.wallet.publicKey,
+      ownerMemberPayer: provider.wallet.publicKey,
+    };
+
+    await program.methods
+      .addCreator(creator, null)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+        memberPayer: null,
+      })
+      .rpc();
+
+    await program.methods
+      .createDraft(
+        { tweet: {} },
+        Array.from(createContentHash("Open draft")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    let twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.openContentCount).toBe(1);
+
+    await expect(
+      program.methods
+        .deregisterTwitterAccount()
+        .accountsPartial(deregisterAccounts)
+        .rpc()
+    ).rejects.toThrow("Twitter account still has open content");
+
+    await program.methods
+      .cancelContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.openContentCount).toBe(0);
+
+    // Records left behind would revive if the Twitter ID registered again
+    const invitee = Keypair.generate().publicKey;
+    const [invitePda] = findInvitePDA(
+      twitterAccountPda,
+      invitee,
+      program.programId
+    );
+    const now = await provider.connection.getBlockTime(
+      await provider.connection.getSlot()
+    );
+    await program.methods
+      .createInvite(invitee, ROLE_ADMIN, new anchor.BN(now + 600), null)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    // Each member or invite is followed by the wallet that paid its rent
+    const leftovers = [
+      { pubkey: creatorMemberPda, isSigner: false, isWritable: true },
+      { pubkey: provider.wallet.publicKey, isSigner: false, isWritable: true },
+      { pubkey: invitePda, isSigner: false, isWritable: true },
+      { pubkey: provider.wallet.publicKey, isSigner: false, isWritable: true },
+    ];
+    await expect(
+      program.methods
+        .deregisterTwitterAccount()
+        .accountsPartial(deregisterAccounts)
+        .remainingAccounts(leftovers)
+        .rpc()
+    ).rejects.toThrow(
+      "Members, invites, content or revisions still belong to this Twitter account"
+    );
+
+    await program.methods
+      .closeContent()
+      .accountsPartial({
+        content: contentPda,
+        payer: provider.wallet.publicKey,
+        authority: provider.wallet.publicKey,
+        twitterAccount: twitterAccountPda,
+        member: null,
+      })
+      .rpc();
+
+    await expect(
+      program.methods
+        .deregisterTwitterAccount()
+        .accountsPartial(deregisterAccounts)
+        .remainingAccounts(leftovers.slice(0, 2))
+        .rpc()
+    ).rejects.toThrow(
+      "Members, invites, content or revisions still belong to this Twitter account"
+    );
+
+    // Other members and pending invites are passed along to be closed too
+    await program.methods
+      .deregisterTwitterAccount()
+      .accountsPartial(deregisterAccounts)
+      .remainingAccounts(leftovers)
+      .rpc();
+
+    for (const closed of [
+      twitterAccountPda,
+      findMemberPDA(
+        twitterAccountPda,
+        provider.wallet.publicKey,
+        program.programId
+      )[0],
+      creatorMemberPda,
+      invitePda,
+      contentPda,
+      deregisterAccounts.handleIndex,
+    ]) {
+      expect(await provider.connection.getAccountInfo(closed)).toBeNull();
+    }
+  });
+
+  it("Validates thread length and tweets root", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const createThread = (contentType: any) =>
+      program.methods
+        .createDraft(contentType, Array.from(createContentHash("Thread")), null)
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+
+    const tooLong = buildThread(
+      Array.from({ length: 51 }, (_, i) => `Tweet ${i + 1}/51`)
+    );
+    await expect(createThread(tooLong.contentType)).rejects.toThrow(
+      "Thread too long"
+    );
+
+    await expect(
+      createThread({
+        thread: { tweetCount: 3, tweetsRoot: new Array(32).fill(0) },
+      })
+    ).rejects.toThrow("Thread needs at least two tweets and a tweets root");
+
+    const thread = buildThread(["One", "Two", "Three"]);
+    await createThread(thread.contentType);
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(contentAccount.contentType).toEqual(thread.contentType);
+  });
+
+  it("Reschedules approved content according to the account policy", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const secondAdmin = Keypair.generate();
+    const inHours = (hours: number) =>
+      new anchor.BN(Math.floor(Date.now() / 1000) + hours * 3600);
+    const contentAccounts = (authority: PublicKey) => ({
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority,
+    });
+
+    await program.methods
+      .addAdmin(secondAdmin.publicKey, null)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+        memberPayer: null,
+      })
+      .rpc();
+    await program.methods
+      .updateRequiredApprovals(1)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Launch tweet")),
+        inHours(2)
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    // The default policy keeps approvals but asks another admin to confirm
+    await program.methods
+      .rescheduleContent(inHours(3))
+      .accountsPartial(contentAccounts(provider.wallet.publicKey))
+      .rpc();
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals).toHaveLength(1);
+
+    await expect(
+      program.methods
+        .approveContent()
+        .accountsPartial(contentAccounts(provider.wallet.publicKey))
+        .rpc()
+    ).rejects.toThrow(
+      "Rescheduled content must be re-approved by another admin"
+    );
+
+    await program.methods
+      .approveContent()
+      .accountsPartial(contentAccounts(secondAdmin.publicKey))
+      .signers([secondAdmin])
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+
+    await program.methods
+      .updateReschedulePolicy({ resetApprovals: {} })
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+      })
+      .rpc();
+    const finalSchedule = inHours(4);
+    await program.methods
+      .rescheduleContent(finalSchedule)
+      .accountsPartial(contentAccounts(provider.wallet.publicKey))
+      .rpc();
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+    expect(contentAccount.approvals).toHaveLength(0);
+    expect(contentAccount.scheduledFor.toNumber()).toBe(
+      finalSchedule.toNumber()
+    );
+  });
+
+  it("Only expires content once its schedule and grace period have passed", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const cranker = Keypair.generate();
+
+    await expect(
+      program.methods
+        .updateExpiryCrankFee(new anchor.BN(10_000_000))
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          owner: provider.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow("Invalid expiry crank fee");
+
+    await program.methods
+      .submitForApproval(
+        { tweet: {} },
+        Array.from(createContentHash("Stale tweet")),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600)
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await expect(
+      program.methods
+        .expireContent()
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          payer: provider.wallet.publicKey,
+          caller: cranker.publicKey,
+        })
+        .signers([cranker])
+        .rpc()
+    ).rejects.toThrow("Content has not passed its schedule and grace period");
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("pendingApproval");
+  });
+
+  it("Validates reply, quote, poll and media content", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const createDraft = (contentType: any) =>
+      program.methods
+        .createDraft(contentType, Array.from(createContentHash("Body")), null)
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+    const hashes = (...items: string[]) =>
+      items.map((item) => Array.from(createContentHash(item)));
+
+    await expect(
+      createDraft({ reply: { inReplyTo: new anchor.BN(0) } })
+    ).rejects.toThrow("Replies and quotes need a target tweet ID");
+    await expect(
+      createDraft({
+        poll: { optionHashes: hashes("Yes"), durationMinutes: 60 },
+      })
+    ).rejects.toThrow("Invalid poll options or duration");
+    await expect(
+      createDraft({
+        poll: { optionHashes: hashes("Yes", "No"), durationMinutes: 1 },
+      })
+    ).rejects.toThrow("Invalid poll options or duration");
+    await expect(
+      createDraft({ media: { mediaHashes: hashes("a", "b", "c", "d", "e") } })
+    ).rejects.toThrow("Invalid media attachments");
+
+    const poll = {
+      poll: { optionHashes: hashes("Yes", "No", "Maybe"), durationMinutes: 1440 },
+    };
+    await createDraft(poll);
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(contentAccount.contentType).toEqual(poll);
+  });
+
+  it("Publishes thread tweets one at a time, in order", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const serviceAuthority = loadServiceAuthority();
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const thread = buildThread(["Part 1", "Part 2", "Part 3"]);
+
+    await program.methods
+      .updateRequiredApprovals(1)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods
+      .submitForApproval(
+        thread.contentType,
+        Array.from(createContentHash("Part 1 Part 2 Part 3")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const markItem = (index: number, tweetHash: Buffer, tweetId: number) =>
+      program.methods
+        .markThreadItemPublished(
+          index,
+          Array.from(tweetHash),
+          thread.proof(index),
+          new anchor.BN(tweetId)
+        )
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          authority: serviceAuthority.publicKey,
+        })
+        .remainingAccounts(
+          approverAccounts(
+            twitterAccountPda,
+            [provider.wallet.publicKey],
+            program.programId
+          )
+        )
+        .signers([serviceAuthority])
+        .rpc();
+
+    await expect(markItem(1, thread.tweetHashes[1], 101)).rejects.toThrow(
+      "Thread tweets must be published in order"
+    );
+    await expect(markItem(0, thread.tweetHashes[1], 100)).rejects.toThrow(
+      "Tweet is not part of the approved thread"
+    );
+
+    await markItem(0, thread.tweetHashes[0], 100);
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+    expect(contentAccount.publishedItems.toNumber()).toBe(0b1);
+
+    // A thread that has started posting can only be finished, not expired
+    const cranker = Keypair.generate();
+    await expect(
+      program.methods
+        .expireContent()
+        .accountsPartial({
+          content: contentPda,
+          twitterAccount: twitterAccountPda,
+          payer: provider.wallet.publicKey,
+          caller: cranker.publicKey,
+        })
+        .signers([cranker])
+        .rpc()
+    ).rejects.toThrow("Invalid content status for operation");
+
+    await markItem(1, thread.tweetHashes[1], 101);
+    await markItem(2, thread.tweetHashes[2], 102);
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("published");
+    expect(contentAccount.publishedTweetIds.map((id) => id.toNumber())).toEqual(
+      [100, 101, 102]
+    );
+  });
+
+  it("Keeps a partly posted thread on course to be finished", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const serviceAuthority = loadServiceAuthority();
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+    const thread = buildThread(["Part 1", "Part 2", "Part 3"]);
+    const [secondAdmin, thirdAdmin] = [Keypair.generate(), Keypair.generate()];
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const contentAccounts = {
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority: provider.wallet.publicKey,
+    };
+    const serviceAccounts = {
+      content: contentPda,
+      twitterAccount: twitterAccountPda,
+      authority: serviceAuthority.publicKey,
+    };
+
+    for (const admin of [secondAdmin, thirdAdmin]) {
+      await program.methods
+        .addAdmin(admin.publicKey, null)
+        .accountsPartial({ ...ownerAccounts, memberPayer: null })
+        .rpc();
+    }
+    await program.methods
+      .updateRequiredApprovals(2)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    await program.methods
+      .submitForApproval(
+        thread.contentType,
+        Array.from(createContentHash("Part 1 Part 2 Part 3")),
+        null
+      )
+      .accountsPartial(contentAccounts)
+      .rpc();
+    await program.methods
+      .approveContent()
+      .accountsPartial({ ...contentAccounts, authority: secondAdmin.publicKey })
+      .remainingAccounts(
+        approverAccounts(
+          twitterAccountPda,
+          [provider.wallet.publicKey],
+          program.programId
+        )
+      )
+      .signers([secondAdmin])
+      .rpc();
+
+    const markItem = (index: number, approvers: PublicKey[]) =>
+      program.methods
+        .markThreadItemPublished(
+          index,
+          Array.from(thread.tweetHashes[index]),
+          thread.proof(index),
+          new anchor.BN(100 + index)
+        )
+        .accountsPartial(serviceAccounts)
+        .remainingAccounts(
+          approverAccounts(twitterAccountPda, approvers, program.programId)
+        )
+        .signers([serviceAuthority])
+        .rpc();
+
+    await markItem(0, [provider.wallet.publicKey, secondAdmin.publicKey]);
+
+    // Its approvals are spent, so they can no longer be revoked
+    await expect(
+      program.methods.revokeApproval().accountsPartial(contentAccounts).rpc()
+    ).rejects.toThrow("Invalid content status for operation");
+
+    // Removing an approver drops the approval but does not requeue the thread
+    await program.methods
+      .removeAdmin(secondAdmin.publicKey)
+      .accountsPartial({
+        ...ownerAccounts,
+        memberPayer: provider.wallet.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: contentPda, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    let contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+    expect(contentAccount.approvals).toHaveLength(1);
+
+    // Nor does publishing its next tweet with a single approval left in force
+    await markItem(1, [provider.wallet.publicKey]);
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("approved");
+    expect(contentAccount.publishedItems.toNumber()).toBe(0b11);
+
+    await expect(
+      program.methods.cancelContent().accountsPartial(contentAccounts).rpc()
+    ).rejects.toThrow("Invalid content status for operation");
+
+    // A failed thread is retried where it left off, never sent back to review
+    await program.methods
+      .markFailed("Rate limited", null)
+      .accountsPartial(serviceAccounts)
+      .signers([serviceAuthority])
+      .rpc();
+    await expect(
+      program.methods
+        .retryContent(true)
+        .accountsPartial({
+          ...contentAccounts,
+          member: findMemberPDA(
+            twitterAccountPda,
+            provider.wallet.publicKey,
+            program.programId
+          )[0],
+        })
+        .rpc()
+    ).rejects.toThrow("Invalid content status for operation");
+
+    contentAccount = await program.account.content.fetch(contentPda);
+    expect(Object.keys(contentAccount.status)[0]).toBe("failed");
+    expect(contentAccount.publishedTweetIds.map((id) => id.toNumber())).toEqual(
+      [100, 101, 0]
+    );
+  });
+
+  it("Emits events for content transitions", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+
+    const createSignature = await program.methods
+      .createDraft(
+        { tweet: {} },
+        Array.from(createContentHash("Evented draft")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const [created] = await fetchCpiEvents(program, createSignature);
+    expect(created.name).toBe("ContentCreated");
+    expect(created.data.content.toString()).toBe(contentPda.toString());
+    expect(created.data.actor.toString()).toBe(
+      provider.wallet.publicKey.toString()
+    );
+
+    const cancelSignature = await program.methods
+      .cancelContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const [canceled] = await fetchCpiEvents(program, cancelSignature);
+    expect(canceled.name).toBe("ContentCanceled");
+    expect(Object.keys(canceled.data.oldStatus)[0]).toBe("draft");
+    expect(Object.keys(canceled.data.newStatus)[0]).toBe("canceled");
+  });
+
+  it("Can close finished content and return rent to the payer", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const [contentPda] = findContentPDA(twitterAccountPda, 0, program.programId);
+
+    await program.methods
+      .createDraft(
+        { tweet: {} },
+        Array.from(createContentHash("Short-lived draft")),
+        null
+      )
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const contentAccount = await program.account.content.fetch(contentPda);
+    expect(contentAccount.payer.toString()).toBe(
+      provider.wallet.publicKey.toString()
+    );
+
+    const closeAccounts = {
+      content: contentPda,
+      payer: provider.wallet.publicKey,
+      authority: provider.wallet.publicKey,
+      twitterAccount: twitterAccountPda,
+      member: null,
+    };
+
+    await expect(
+      program.methods.closeContent().accountsPartial(closeAccounts).rpc()
+    ).rejects.toThrow(
+      "Content must be published, canceled or expired before it can be closed"
+    );
+
+    await program.methods
+      .cancelContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    await program.methods.closeContent().accountsPartial(closeAccounts).rpc();
+
+    expect(await provider.connection.getAccountInfo(contentPda)).toBeNull();
+  });
 });
@@ -3,8 +3,16 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Helm } from "../target/types/helm";
-import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import {
+  PublicKey,
+  SystemProgram,
+  Keypair,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import { describe, it, expect, jest } from "@jest/globals";
+import { keccak_256 } from "js-sha3";
+import * as fs from "fs";
 // import { expect } from "jest";
 
 // Helper function to generate unique twitter IDs
@@ -12,13 +20,156 @@ const generateUniqueTwitterId = () => {
   return Math.floor(Math.random() * 1000000000).toString();
 };
 
-const findCreatorListPDA = (twitterId: string, programId: PublicKey) => {
+// Handles are unique on-chain, so derive one per test account
+const generateUniqueHandle = (twitterId: string) => `h_${twitterId}`;
+
+export const findHandleIndexPDA = (handle: string, programId: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("handle-index"), Buffer.from(handle.toLowerCase())],
+    programId
+  );
+};
+
+export const findMemberPDA = (
+  twitterAccountPda: PublicKey,
+  wallet: PublicKey,
+  programId: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("member"), twitterAccountPda.toBuffer(), wallet.toBuffer()],
+    programId
+  );
+};
+
+export const findInvitePDA = (
+  twitterAccountPda: PublicKey,
+  invitee: PublicKey,
+  programId: PublicKey
+) => {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("creator-list"), Buffer.from(twitterId)],
+    [Buffer.from("invite"), twitterAccountPda.toBuffer(), invitee.toBuffer()],
     programId
   );
 };
 
+// Role bits stored in Member.roles
+export const ROLE_OWNER = 1 << 0;
+export const ROLE_ADMIN = 1 << 1;
+export const ROLE_CREATOR = 1 << 2;
+export const ROLE_PUBLISHER = 1 << 3;
+export const ROLE_VIEWER = 1 << 4;
+
+// Keypair of the service authority that signs verification attestations; the
+// fixture matches SERVICE_AUTHORITY in programs built with the localnet feature
+export const loadServiceAuthority = () => {
+  const keypairPath =
+    process.env.HELM_SERVICE_AUTHORITY_KEYPAIR ??
+    "tests/fixtures/service-authority.json";
+  return Keypair.fromSecretKey(
+    Uint8Array.from(JSON.parse(fs.readFileSync(keypairPath, "utf8")))
+  );
+};
+
+// Builds the ed25519 attestation that must precede verifyTwitterAccount
+export const createVerificationAttestation = (
+  twitterAccountPda: PublicKey,
+  twitterId: string,
+  owner: PublicKey,
+  twitterHandle: string,
+  signer: Keypair
+) => {
+  const message = Buffer.concat([
+    Buffer.from("helm:verify-account"),
+    twitterAccountPda.toBuffer(),
+    Buffer.from(twitterId),
+    owner.toBuffer(),
+    Buffer.from(twitterHandle),
+  ]);
+  return Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message,
+  });
+};
+
+// Builds the ed25519 attestation that must precede reclaimTwitterAccount
+export const createReclaimAttestation = (
+  twitterAccountPda: PublicKey,
+  twitterId: string,
+  claimant: PublicKey,
+  memberEpoch: number,
+  expiresAt: number,
+  twitterHandle: string,
+  signer: Keypair
+) => {
+  const epoch = Buffer.alloc(4);
+  epoch.writeUInt32LE(memberEpoch);
+  const message = Buffer.concat([
+    Buffer.from("helm:reclaim-account"),
+    twitterAccountPda.toBuffer(),
+    Buffer.from(twitterId),
+    claimant.toBuffer(),
+    epoch,
+    new anchor.BN(expiresAt).toArrayLike(Buffer, "le", 8),
+    Buffer.from(twitterHandle),
+  ]);
+  return Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message,
+  });
+};
+
+// Builds the ed25519 attestation that must precede updateHandle
+export const createHandleAttestation = (
+  twitterAccountPda: PublicKey,
+  twitterId: string,
+  handle: string,
+  signer: Keypair
+) => {
+  const idLength = Buffer.alloc(4);
+  idLength.writeUInt32LE(Buffer.byteLength(twitterId));
+  const message = Buffer.concat([
+    Buffer.from("helm:update-handle"),
+    twitterAccountPda.toBuffer(),
+    idLength,
+    Buffer.from(twitterId),
+    Buffer.from(handle),
+  ]);
+  return Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message,
+  });
+};
+
+export const verifyTwitterAccount = async (
+  program: Program<Helm>,
+  provider: anchor.AnchorProvider,
+  twitterAccountPda: PublicKey,
+  twitterId: string
+) => {
+  const { twitterHandle } = await program.account.twitterAccount.fetch(
+    twitterAccountPda
+  );
+  const attestationIx = createVerificationAttestation(
+    twitterAccountPda,
+    twitterId,
+    provider.wallet.publicKey,
+    twitterHandle,
+    loadServiceAuthority()
+  );
+
+  await program.methods
+    .verifyTwitterAccount()
+    .accountsPartial({
+      twitterAccount: twitterAccountPda,
+      handleIndex: findHandleIndexPDA(twitterHandle, program.programId)[0],
+      attestationConfig: null,
+      owner: provider.wallet.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    })
+    .preInstructions([attestationIx])
+    .rpc();
+};
+
 describe("helm", () => {
   // Configure the client to use the local cluster
   const provider = anchor.AnchorProvider.env();
@@ -29,7 +180,7 @@ describe("helm", () => {
   it("Can register a twitter account", async () => {
     // Test data
     const twitterId = "123456789";
-    const twitterHandle = "test_handle";
+    const twitterHandle = generateUniqueHandle(twitterId);
 
     // Derive PDA for twitter account
     const [twitterAccountPda] = PublicKey.findProgramAddressSync(
@@ -37,20 +188,11 @@ describe("helm", () => {
       program.programId
     );
 
-    // Derive PDA for admin list
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
-      program.programId
-    );
-    const [creatorListPda] = findCreatorListPDA(twitterId, program.programId);
-
     // Register the twitter account
     const tx = await program.methods
       .registerTwitterAccount(twitterId, twitterHandle)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
         owner: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -68,17 +210,30 @@ describe("helm", () => {
     expect(twitterAccount.twitterId).toBe(twitterId);
     expect(twitterAccount.twitterHandle).toBe(twitterHandle);
     expect(twitterAccount.isVerified).toBe(false);
-    expect(twitterAccount.requiredApprovals).toBe(3); // Default value
+    expect(twitterAccount.approvalPolicy).toEqual({
+      threshold: { required: 3 },
+    }); // Default value
 
-    // Fetch and verify admin list
-    const adminList = await program.account.adminList.fetch(adminListPda);
-    expect(adminList.admins).toHaveLength(1);
-    expect(adminList.admins[0].toString()).toBe(
-      provider.wallet.publicKey.toString()
+    expect(twitterAccount.adminCount).toBe(1);
+
+    // The owner's membership carries the Owner and Admin roles
+    const [ownerMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      provider.wallet.publicKey,
+      program.programId
     );
-    expect(adminList.authority.toString()).toBe(
+    const ownerMember = await program.account.member.fetch(ownerMemberPda);
+    expect(ownerMember.wallet.toString()).toBe(
       provider.wallet.publicKey.toString()
     );
+    expect(ownerMember.roles).toBe(ROLE_OWNER | ROLE_ADMIN);
+
+    // The handle is only indexed once verification vouches for it
+    expect(
+      await provider.connection.getAccountInfo(
+        findHandleIndexPDA(twitterHandle, program.programId)[0]
+      )
+    ).toBeNull();
     // try {
     //   // Register the twitter account
     //   const tx = await program.methods
@@ -130,19 +285,11 @@ describe("helm", () => {
       program.programId
     );
 
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
-      program.programId
-    );
-    const [creatorListPda] = findCreatorListPDA(twitterId, program.programId);
-
     await expect(
       program.methods
         .registerTwitterAccount(twitterId, invalidHandle)
         .accountsPartial({
           twitterAccount: twitterAccountPda,
-          adminList: adminListPda,
-          creatorList: creatorListPda,
           owner: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -153,7 +300,7 @@ describe("helm", () => {
   it("Can add an admin", async () => {
     // Use unique twitter ID
     const twitterId = generateUniqueTwitterId();
-    const twitterHandle = "test_handle";
+    const twitterHandle = generateUniqueHandle(twitterId);
     const newAdmin = Keypair.generate();
 
     const [twitterAccountPda] = PublicKey.findProgramAddressSync(
@@ -161,54 +308,44 @@ describe("helm", () => {
       program.programId
     );
 
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
-      program.programId
-    );
-
-    const [creatorListPda] = findCreatorListPDA(twitterId, program.programId);
-
     // Register the twitter account
     await program.methods
       .registerTwitterAccount(twitterId, twitterHandle)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
         owner: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
 
     // Verify the twitter account
-    await program.methods
-      .verifyTwitterAccount()
-      .accountsPartial({
-        twitterAccount: twitterAccountPda,
-        owner: provider.wallet.publicKey,
-      })
-      .rpc();
+    await verifyTwitterAccount(program, provider, twitterAccountPda, twitterId);
 
     // Now add the new admin
     await program.methods
-      .addAdmin(newAdmin.publicKey)
+      .addAdmin(newAdmin.publicKey, null)
       .accountsPartial({
-        adminList: adminListPda,
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
+        memberPayer: null,
       })
       .rpc();
 
     // Verify the admin was added
-    const adminList = await program.account.adminList.fetch(adminListPda);
-    expect(adminList.admins).toHaveLength(2);
-    expect(adminList.admins[1].toString()).toBe(newAdmin.publicKey.toString());
+    const member = await program.account.member.fetch(
+      findMemberPDA(twitterAccountPda, newAdmin.publicKey, program.programId)[0]
+    );
+    expect(member.roles).toBe(ROLE_ADMIN);
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(2);
   });
 
   it("Can remove an admin and prevents removing last admin", async () => {
     // Use unique twitter ID
     const twitterId = generateUniqueTwitterId();
-    const twitterHandle = "test_handle";
+    const twitterHandle = generateUniqueHandle(twitterId);
     const newAdmin = Keypair.generate();
 
     const [twitterAccountPda] = PublicKey.findProgramAddressSync(
@@ -216,40 +353,25 @@ describe("helm", () => {
       program.programId
     );
 
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
-      program.programId
-    );
-
-    const [creatorListPda] = findCreatorListPDA(twitterId, program.programId);
-
     // Register account and verify
     await program.methods
       .registerTwitterAccount(twitterId, twitterHandle)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
         owner: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
 
-    await program.methods
-      .verifyTwitterAccount()
-      .accountsPartial({
-        twitterAccount: twitterAccountPda,
-        owner: provider.wallet.publicKey,
-      })
-      .rpc();
+    await verifyTwitterAccount(program, provider, twitterAccountPda, twitterId);
 
     // Add new admin
     await program.methods
-      .addAdmin(newAdmin.publicKey)
+      .addAdmin(newAdmin.publicKey, null)
       .accountsPartial({
-        adminList: adminListPda,
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
+        memberPayer: null,
       })
       .rpc();
 
@@ -257,24 +379,32 @@ describe("helm", () => {
     await program.methods
       .removeAdmin(newAdmin.publicKey)
       .accountsPartial({
-        adminList: adminListPda,
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
+        memberPayer: provider.wallet.publicKey,
       })
       .rpc();
 
-    // Verify admin was removed
-    const adminList = await program.account.adminList.fetch(adminListPda);
-    expect(adminList.admins).toHaveLength(1);
+    // Verify admin was removed; the emptied membership is closed
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      newAdmin.publicKey,
+      program.programId
+    );
+    expect(await provider.connection.getAccountInfo(memberPda)).toBeNull();
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(1);
 
     // Try to remove the last admin
     await expect(
       program.methods
         .removeAdmin(provider.wallet.publicKey)
         .accountsPartial({
-          adminList: adminListPda,
           twitterAccount: twitterAccountPda,
           owner: provider.wallet.publicKey,
+          memberPayer: provider.wallet.publicKey,
         })
         .rpc()
     ).rejects.toThrow("Cannot remove last admin");
@@ -282,7 +412,7 @@ describe("helm", () => {
 
   it("Cannot add admin to unverified account", async () => {
     const twitterId = generateUniqueTwitterId();
-    const twitterHandle = "unique_handle";
+    const twitterHandle = generateUniqueHandle(twitterId);
     const newAdmin = Keypair.generate();
 
     const [twitterAccountPda] = PublicKey.findProgramAddressSync(
@@ -290,19 +420,11 @@ describe("helm", () => {
       program.programId
     );
 
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
-      program.programId
-    );
-    const [creatorListPda] = findCreatorListPDA(twitterId, program.programId);
-
     // Register account
     await program.methods
       .registerTwitterAccount(twitterId, twitterHandle)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
         owner: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -311,11 +433,11 @@ describe("helm", () => {
     // Try to add admin to unverified account
     await expect(
       program.methods
-        .addAdmin(newAdmin.publicKey)
+        .addAdmin(newAdmin.publicKey, null)
         .accountsPartial({
-          adminList: adminListPda,
           twitterAccount: twitterAccountPda,
           owner: provider.wallet.publicKey,
+          memberPayer: null,
         })
         .rpc()
     ).rejects.toThrow("Twitter account not verified");
@@ -323,7 +445,7 @@ describe("helm", () => {
 
   it("Can add a creator", async () => {
     const twitterId = generateUniqueTwitterId();
-    const twitterHandle = "test_handle";
+    const twitterHandle = generateUniqueHandle(twitterId);
     const newCreator = Keypair.generate();
 
     const [twitterAccountPda] = PublicKey.findProgramAddressSync(
@@ -331,54 +453,42 @@ describe("helm", () => {
       program.programId
     );
 
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
-      program.programId
-    );
-
-    const [creatorListPda] = findCreatorListPDA(twitterId, program.programId);
-
     // Register and initialize all accounts
     await program.methods
       .registerTwitterAccount(twitterId, twitterHandle)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
         owner: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
 
     // Verify the account
-    await program.methods
-      .verifyTwitterAccount()
-      .accountsPartial({
-        twitterAccount: twitterAccountPda,
-        owner: provider.wallet.publicKey,
-      })
-      .rpc();
+    await verifyTwitterAccount(program, provider, twitterAccountPda, twitterId);
 
     // Add the creator
     await program.methods
-      .addCreator(newCreator.publicKey)
+      .addCreator(newCreator.publicKey, null)
       .accountsPartial({
-        creatorList: creatorListPda,
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
+        memberPayer: null,
       })
       .rpc();
 
     // Verify creator was added
-    const creatorList = await program.account.creatorList.fetch(creatorListPda);
-    expect(creatorList.creators.map((p) => p.toString())).toContain(
-      newCreator.publicKey.toString()
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      newCreator.publicKey,
+      program.programId
     );
+    const member = await program.account.member.fetch(memberPda);
+    expect(member.roles).toBe(ROLE_CREATOR);
   });
 
   it("Can remove a creator", async () => {
     const twitterId = generateUniqueTwitterId();
-    const twitterHandle = "test_handle";
+    const twitterHandle = generateUniqueHandle(twitterId);
     const creator = Keypair.generate();
 
     const [twitterAccountPda] = PublicKey.findProgramAddressSync(
@@ -386,122 +496,999 @@ describe("helm", () => {
       program.programId
     );
 
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
+    // Register and verify account
+    await program.methods
+      .registerTwitterAccount(twitterId, twitterHandle)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await verifyTwitterAccount(program, provider, twitterAccountPda, twitterId);
+
+    // Add creator
+    await program.methods
+      .addCreator(creator.publicKey, null)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+        memberPayer: null,
+      })
+      .rpc();
+
+    // Remove creator
+    await program.methods
+      .removeCreator(creator.publicKey)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+        memberPayer: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    // Verify creator was removed
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      creator.publicKey,
       program.programId
     );
+    expect(await provider.connection.getAccountInfo(memberPda)).toBeNull();
+  });
 
-    const [creatorListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("creator-list"), Buffer.from(twitterId)],
+  it("Cannot add duplicate creator", async () => {
+    const twitterId = generateUniqueTwitterId();
+    const twitterHandle = generateUniqueHandle(twitterId);
+    const creator = Keypair.generate();
+
+    const [twitterAccountPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("twitter-account"), Buffer.from(twitterId)],
       program.programId
     );
 
-    // Register and verify account
+    // Setup account
     await program.methods
       .registerTwitterAccount(twitterId, twitterHandle)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
         owner: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
 
+    await verifyTwitterAccount(program, provider, twitterAccountPda, twitterId);
+
+    // Add creator first time
     await program.methods
-      .verifyTwitterAccount()
+      .addCreator(creator.publicKey, null)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
+        memberPayer: null,
       })
       .rpc();
 
-    // Add creator
+    // Try to add same creator again
+    await expect(
+      program.methods
+        .addCreator(creator.publicKey, null)
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          owner: provider.wallet.publicKey,
+          memberPayer: null,
+        })
+        .rpc()
+    ).rejects.toThrow("Creator already exists");
+  });
+
+  it("Can configure maximum publish attempts", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+
     await program.methods
-      .addCreator(creator.publicKey)
+      .updateMaxPublishAttempts(5)
       .accountsPartial({
-        creatorList: creatorListPda,
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
       })
       .rpc();
 
-    // Remove creator
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.maxPublishAttempts).toBe(5);
+
+    // Zero attempts would make every failure final
+    await expect(
+      program.methods
+        .updateMaxPublishAttempts(0)
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          owner: provider.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow("Invalid maximum publish attempts");
+  });
+
+  it("Keeps required approvals within the admin count", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const newAdmin = Keypair.generate();
+    const thresholdAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+
+    // A single admin cannot satisfy two approvals
+    await expect(
+      program.methods
+        .updateRequiredApprovals(2)
+        .accountsPartial(thresholdAccounts)
+        .rpc()
+    ).rejects.toThrow("Required approvals exceed the number of admins");
+
     await program.methods
-      .removeCreator(creator.publicKey)
+      .addAdmin(newAdmin.publicKey, null)
       .accountsPartial({
-        creatorList: creatorListPda,
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
+        memberPayer: null,
       })
       .rpc();
 
-    // Verify creator was removed
-    const creatorList = await program.account.creatorList.fetch(creatorListPda);
-    expect(creatorList.creators).not.toContain(creator.publicKey);
+    await program.methods
+      .updateRequiredApprovals(2)
+      .accountsPartial(thresholdAccounts)
+      .rpc();
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.approvalPolicy).toEqual({
+      threshold: { required: 2 },
+    });
+
+    // Removing either admin would make two approvals unreachable
+    await expect(
+      program.methods
+        .removeAdmin(newAdmin.publicKey)
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          owner: provider.wallet.publicKey,
+          memberPayer: provider.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow("Required approvals exceed the number of admins");
   });
 
-  it("Cannot add duplicate creator", async () => {
+  it("Cannot verify an account without a service attestation", async () => {
     const twitterId = generateUniqueTwitterId();
-    const twitterHandle = "test_handle";
-    const creator = Keypair.generate();
+    const twitterHandle = generateUniqueHandle(twitterId);
 
     const [twitterAccountPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("twitter-account"), Buffer.from(twitterId)],
       program.programId
     );
 
-    const [adminListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("admin-list"), Buffer.from(twitterId)],
-      program.programId
+    await program.methods
+      .registerTwitterAccount(twitterId, twitterHandle)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        owner: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const accounts = {
+      twitterAccount: twitterAccountPda,
+      handleIndex: findHandleIndexPDA(twitterHandle, program.programId)[0],
+      attestationConfig: null,
+      owner: provider.wallet.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    };
+
+    await expect(
+      program.methods.verifyTwitterAccount().accountsPartial(accounts).rpc()
+    ).rejects.toThrow("Missing service attestation");
+
+    // The owner cannot vouch for themselves
+    const ownerKeypair = (provider.wallet as anchor.Wallet).payer;
+    await expect(
+      program.methods
+        .verifyTwitterAccount()
+        .accountsPartial(accounts)
+        .preInstructions([
+          createVerificationAttestation(
+            twitterAccountPda,
+            twitterId,
+            provider.wallet.publicKey,
+            twitterHandle,
+            ownerKeypair
+          ),
+        ])
+        .rpc()
+    ).rejects.toThrow("Invalid service attestation");
+  });
+
+  it("Cannot reclaim an account without a service attestation", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const claimant = Keypair.generate();
+
+    await expect(
+      program.methods
+        .reclaimTwitterAccount(
+          new anchor.BN(Math.floor(Date.now() / 1000) + 600)
+        )
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          attestationConfig: null,
+          claimant: claimant.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([claimant])
+        .rpc()
+    ).rejects.toThrow("Missing service attestation");
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.owner.toString()).toBe(
+      provider.wallet.publicKey.toString()
     );
+  });
 
-    const [creatorListPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("creator-list"), Buffer.from(twitterId)],
+  it("Can reclaim an account with a fresh service attestation", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const { twitterId, twitterHandle } =
+      await program.account.twitterAccount.fetch(twitterAccountPda);
+    const handleIndexPda = findHandleIndexPDA(
+      twitterHandle,
       program.programId
+    )[0];
+    const serviceAuthority = loadServiceAuthority();
+    const claimant = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      claimant.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
     );
+    await provider.connection.confirmTransaction(signature);
 
-    // Setup account
+    const reclaimAccounts = {
+      twitterAccount: twitterAccountPda,
+      handleIndex: handleIndexPda,
+      attestationConfig: null,
+      claimant: claimant.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    };
+    const reclaim = (
+      memberEpoch: number,
+      expiresAt: number,
+      handle = twitterHandle
+    ) =>
+      program.methods
+        .reclaimTwitterAccount(new anchor.BN(expiresAt))
+        .accountsPartial(reclaimAccounts)
+        .preInstructions([
+          createReclaimAttestation(
+            twitterAccountPda,
+            twitterId,
+            claimant.publicKey,
+            memberEpoch,
+            expiresAt,
+            handle,
+            serviceAuthority
+          ),
+        ])
+        .signers([claimant])
+        .rpc();
+    const now = Math.floor(Date.now() / 1000);
+
+    await expect(reclaim(0, now - 60)).rejects.toThrow(
+      "Service attestation expired"
+    );
+    // The attestation must vouch for the handle the account is known by
+    await expect(reclaim(0, now + 600, "someone_else")).rejects.toThrow(
+      "Invalid service attestation"
+    );
+
+    await reclaim(0, now + 600);
+
+    let twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.owner.toString()).toBe(claimant.publicKey.toString());
+    expect(twitterAccount.memberEpoch).toBe(1);
+    expect(twitterAccount.adminCount).toBe(1);
+    const claimantMember = await program.account.member.fetch(
+      findMemberPDA(twitterAccountPda, claimant.publicKey, program.programId)[0]
+    );
+    expect(claimantMember.roles).toBe(ROLE_OWNER | ROLE_ADMIN);
+    expect(claimantMember.epoch).toBe(1);
+    const handleIndex = await program.account.handleIndex.fetch(handleIndexPda);
+    expect(handleIndex.twitterAccount.toString()).toBe(
+      twitterAccountPda.toString()
+    );
+
+    // Hand the account on, then try to take it back with the used attestation
     await program.methods
-      .registerTwitterAccount(twitterId, twitterHandle)
+      .proposeOwner(provider.wallet.publicKey, new anchor.BN(now + 600))
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
-        owner: provider.wallet.publicKey,
-        systemProgram: SystemProgram.programId,
+        owner: claimant.publicKey,
+      })
+      .signers([claimant])
+      .rpc();
+    await program.methods
+      .acceptOwnership()
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        previousOwnerMemberPayer: claimant.publicKey,
+        newOwner: provider.wallet.publicKey,
       })
       .rpc();
 
+    await expect(reclaim(0, now + 600)).rejects.toThrow(
+      "Invalid service attestation"
+    );
+    twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.owner.toString()).toBe(
+      provider.wallet.publicKey.toString()
+    );
+  });
+
+  it("Can transfer ownership in two steps", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const newOwner = Keypair.generate();
+    const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+    // The new owner pays for their own membership
+    const signature = await provider.connection.requestAirdrop(
+      newOwner.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+
     await program.methods
-      .verifyTwitterAccount()
+      .proposeOwner(newOwner.publicKey, new anchor.BN(expiresAt))
       .accountsPartial({
         twitterAccount: twitterAccountPda,
         owner: provider.wallet.publicKey,
       })
       .rpc();
 
-    // Add creator first time
     await program.methods
-      .addCreator(creator.publicKey)
+      .acceptOwnership()
       .accountsPartial({
-        creatorList: creatorListPda,
         twitterAccount: twitterAccountPda,
-        owner: provider.wallet.publicKey,
+        previousOwnerMemberPayer: provider.wallet.publicKey,
+        newOwner: newOwner.publicKey,
       })
+      .signers([newOwner])
       .rpc();
 
-    // Try to add same creator again
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.owner.toString()).toBe(newOwner.publicKey.toString());
+    expect(twitterAccount.pendingOwner).toBeNull();
+
+    expect(twitterAccount.adminCount).toBe(1);
+
+    // The Owner and Admin roles move over; the emptied old membership is closed
+    const newOwnerMember = await program.account.member.fetch(
+      findMemberPDA(twitterAccountPda, newOwner.publicKey, program.programId)[0]
+    );
+    expect(newOwnerMember.roles).toBe(ROLE_OWNER | ROLE_ADMIN);
+    const [previousOwnerMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      provider.wallet.publicKey,
+      program.programId
+    );
+    expect(
+      await provider.connection.getAccountInfo(previousOwnerMemberPda)
+    ).toBeNull();
+  });
+
+  it("Gives up the previous owner's admin slot when an admin takes over", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const admin = Keypair.generate();
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const acceptOwnership = () =>
+      program.methods
+        .acceptOwnership()
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          previousOwnerMemberPayer: provider.wallet.publicKey,
+          newOwner: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    await program.methods
+      .addAdmin(admin.publicKey, null)
+      .accountsPartial({ ...ownerAccounts, memberPayer: null })
+      .rpc();
+    await program.methods
+      .updateRequiredApprovals(2)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+    await program.methods
+      .proposeOwner(
+        admin.publicKey,
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600)
+      )
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    // Two admins become one, which would leave two approvals out of reach
+    await expect(acceptOwnership()).rejects.toThrow(
+      "Required approvals exceed the number of admins"
+    );
+
+    await program.methods
+      .updateRequiredApprovals(1)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+    await acceptOwnership();
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.owner.toString()).toBe(admin.publicKey.toString());
+    expect(twitterAccount.adminCount).toBe(1);
+  });
+
+  it("Can grant and revoke arbitrary roles", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const wallet = Keypair.generate().publicKey;
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      wallet,
+      program.programId
+    );
+    const memberAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+      memberPayer: provider.wallet.publicKey,
+    };
+
+    // Ownership only moves through the ownership transfer
     await expect(
       program.methods
-        .addCreator(creator.publicKey)
+        .grantRoles(wallet, ROLE_OWNER, null)
+        .accountsPartial(memberAccounts)
+        .rpc()
+    ).rejects.toThrow("Invalid member role");
+
+    await program.methods
+      .grantRoles(wallet, ROLE_PUBLISHER | ROLE_VIEWER, null)
+      .accountsPartial(memberAccounts)
+      .rpc();
+    await program.methods
+      .addCreator(wallet, null)
+      .accountsPartial(memberAccounts)
+      .rpc();
+
+    let member = await program.account.member.fetch(memberPda);
+    expect(member.roles).toBe(ROLE_CREATOR | ROLE_PUBLISHER | ROLE_VIEWER);
+
+    await expect(
+      program.methods
+        .revokeRoles(wallet, ROLE_ADMIN)
+        .accountsPartial(memberAccounts)
+        .rpc()
+    ).rejects.toThrow("Admin does not exist");
+
+    await program.methods
+      .revokeRoles(wallet, ROLE_PUBLISHER | ROLE_VIEWER)
+      .accountsPartial(memberAccounts)
+      .rpc();
+    member = await program.account.member.fetch(memberPda);
+    expect(member.roles).toBe(ROLE_CREATOR);
+
+    await program.methods
+      .removeCreator(wallet)
+      .accountsPartial(memberAccounts)
+      .rpc();
+    expect(await provider.connection.getAccountInfo(memberPda)).toBeNull();
+  });
+
+  it("Prunes expired memberships", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const admin = Keypair.generate().publicKey;
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      admin,
+      program.programId
+    );
+    const memberAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+      memberPayer: null,
+    };
+    const pruneAccounts = {
+      twitterAccount: twitterAccountPda,
+      caller: provider.wallet.publicKey,
+    };
+    // Each pruned record is followed by the wallet that paid its rent
+    const prunedMember = [
+      { pubkey: memberPda, isSigner: false, isWritable: true },
+      { pubkey: provider.wallet.publicKey, isSigner: false, isWritable: true },
+    ];
+    const now = async () =>
+      provider.connection.getBlockTime(await provider.connection.getSlot());
+    const expiresAt = (await now()) + 2;
+
+    // The owner's membership never expires
+    await expect(
+      program.methods
+        .grantRoles(
+          provider.wallet.publicKey,
+          ROLE_VIEWER,
+          new anchor.BN(expiresAt)
+        )
+        .accountsPartial(memberAccounts)
+        .rpc()
+    ).rejects.toThrow("Invalid membership expiry");
+
+    await program.methods
+      .addAdmin(admin, new anchor.BN(expiresAt))
+      .accountsPartial(memberAccounts)
+      .rpc();
+    let twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(2);
+
+    await expect(
+      program.methods
+        .pruneExpiredMembers()
+        .accountsPartial(pruneAccounts)
+        .remainingAccounts(prunedMember)
+        .rpc()
+    ).rejects.toThrow("Member has not expired");
+
+    while ((await now()) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    await program.methods
+      .pruneExpiredMembers()
+      .accountsPartial(pruneAccounts)
+      .remainingAccounts(prunedMember)
+      .rpc();
+
+    twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(1);
+    expect(await provider.connection.getAccountInfo(memberPda)).toBeNull();
+  });
+
+  it("Keeps an admin's expiry when they are granted another role", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const admin = Keypair.generate().publicKey;
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      admin,
+      program.programId
+    );
+    const memberAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+      memberPayer: null,
+    };
+    const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+
+    await program.methods
+      .addAdmin(admin, new anchor.BN(expiresAt))
+      .accountsPartial(memberAccounts)
+      .rpc();
+    // A grant without the admin role cannot make the admin permanent
+    await program.methods
+      .grantRoles(admin, ROLE_CREATOR, null)
+      .accountsPartial(memberAccounts)
+      .rpc();
+
+    const member = await program.account.member.fetch(memberPda);
+    expect(member.roles).toBe(ROLE_ADMIN | ROLE_CREATOR);
+    expect(member.expiresAt.toNumber()).toBe(expiresAt);
+    let twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminExpiries.map((e) => e.toNumber())).toEqual([
+      expiresAt,
+    ]);
+
+    // Taking the admin role away forgets the expiry along with the admin slot
+    await program.methods
+      .revokeRoles(admin, ROLE_ADMIN)
+      .accountsPartial(memberAccounts)
+      .rpc();
+
+    twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(1);
+    expect(twitterAccount.adminExpiries).toHaveLength(0);
+  });
+
+  it("Keeps an expired admin the approval groups still need", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const admin = Keypair.generate().publicKey;
+    const [ownerMemberPda] = findMemberPDA(
+      twitterAccountPda,
+      provider.wallet.publicKey,
+      program.programId
+    );
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      admin,
+      program.programId
+    );
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const prune = () =>
+      program.methods
+        .pruneExpiredMembers()
         .accountsPartial({
-          creatorList: creatorListPda,
           twitterAccount: twitterAccountPda,
-          owner: provider.wallet.publicKey,
+          caller: provider.wallet.publicKey,
         })
+        .remainingAccounts([
+          { pubkey: memberPda, isSigner: false, isWritable: true },
+          {
+            pubkey: provider.wallet.publicKey,
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
+        .rpc();
+    const now = async () =>
+      provider.connection.getBlockTime(await provider.connection.getSlot());
+    const expiresAt = (await now()) + 2;
+
+    await program.methods
+      .addAdmin(admin, new anchor.BN(expiresAt))
+      .accountsPartial({ ...ownerAccounts, memberPayer: null })
+      .rpc();
+    await program.methods
+      .updateApprovalPolicy({
+        groups: {
+          groups: [
+            { name: "legal", members: [provider.wallet.publicKey] },
+            { name: "marketing", members: [admin] },
+          ],
+        },
+      })
+      .accountsPartial(ownerAccounts)
+      .remainingAccounts([
+        { pubkey: ownerMemberPda, isSigner: false, isWritable: false },
+        { pubkey: memberPda, isSigner: false, isWritable: false },
+      ])
+      .rpc();
+
+    while ((await now()) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    // Pruning would empty the marketing group
+    await expect(prune()).rejects.toThrow(
+      "Required approvals exceed the number of admins"
+    );
+
+    await program.methods
+      .updateRequiredApprovals(1)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+    await prune();
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(1);
+    expect(await provider.connection.getAccountInfo(memberPda)).toBeNull();
+  });
+
+  it("Adds admins only once they accept an invite", async () => {
+    const { twitterAccountPda } = await setupVerifiedAccount(program, provider);
+    const invitee = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      invitee.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+
+    const [invitePda] = findInvitePDA(
+      twitterAccountPda,
+      invitee.publicKey,
+      program.programId
+    );
+    const [memberPda] = findMemberPDA(
+      twitterAccountPda,
+      invitee.publicKey,
+      program.programId
+    );
+    const ownerAccounts = {
+      twitterAccount: twitterAccountPda,
+      owner: provider.wallet.publicKey,
+    };
+    const acceptAccounts = {
+      twitterAccount: twitterAccountPda,
+      invitedBy: provider.wallet.publicKey,
+      invitee: invitee.publicKey,
+    };
+    const now = await provider.connection.getBlockTime(
+      await provider.connection.getSlot()
+    );
+    const codeHash = Buffer.from(keccak_256("welcome-aboard"), "hex");
+
+    await expect(
+      program.methods
+        .createInvite(
+          invitee.publicKey,
+          ROLE_ADMIN,
+          new anchor.BN(now - 1),
+          null
+        )
+        .accountsPartial(ownerAccounts)
         .rpc()
-    ).rejects.toThrow("Creator already exists");
+    ).rejects.toThrow("Invalid invite expiry");
+
+    await program.methods
+      .createInvite(
+        invitee.publicKey,
+        ROLE_ADMIN,
+        new anchor.BN(now + 600),
+        Array.from(codeHash)
+      )
+      .accountsPartial(ownerAccounts)
+      .rpc();
+
+    // Nothing changes until the invitee accepts
+    expect(await provider.connection.getAccountInfo(memberPda)).toBeNull();
+
+    await expect(
+      program.methods
+        .acceptInvite("wrong-code")
+        .accountsPartial(acceptAccounts)
+        .signers([invitee])
+        .rpc()
+    ).rejects.toThrow("Invalid invite code");
+
+    await program.methods
+      .acceptInvite("welcome-aboard")
+      .accountsPartial(acceptAccounts)
+      .signers([invitee])
+      .rpc();
+
+    const member = await program.account.member.fetch(memberPda);
+    expect(member.roles).toBe(ROLE_ADMIN);
+    expect(member.addedBy.toString()).toBe(
+      provider.wallet.publicKey.toString()
+    );
+    expect(member.payer.toString()).toBe(invitee.publicKey.toString());
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.adminCount).toBe(2);
+    expect(await provider.connection.getAccountInfo(invitePda)).toBeNull();
+
+    // The invitee paid for its membership, so the rent goes back to it
+    const removeInvitee = (memberPayer: PublicKey) =>
+      program.methods
+        .removeAdmin(invitee.publicKey)
+        .accountsPartial({ ...ownerAccounts, memberPayer })
+        .rpc();
+    await expect(removeInvitee(provider.wallet.publicKey)).rejects.toThrow(
+      "A member record's rent must go back to whoever paid for it"
+    );
+    const inviteeBalance = await provider.connection.getBalance(
+      invitee.publicKey
+    );
+    await removeInvitee(invitee.publicKey);
+    expect(await provider.connection.getAccountInfo(memberPda)).toBeNull();
+    expect(
+      await provider.connection.getBalance(invitee.publicKey)
+    ).toBeGreaterThan(inviteeBalance);
+
+    // Pending invites can be withdrawn by the owner
+    const other = Keypair.generate().publicKey;
+    const [otherInvitePda] = findInvitePDA(
+      twitterAccountPda,
+      other,
+      program.programId
+    );
+    await program.methods
+      .createInvite(other, ROLE_CREATOR, new anchor.BN(now + 600), null)
+      .accountsPartial(ownerAccounts)
+      .rpc();
+    const revokeAccounts = {
+      ...ownerAccounts,
+      invite: otherInvitePda,
+      invitedBy: provider.wallet.publicKey,
+    };
+    await expect(
+      program.methods
+        .revokeInvite()
+        .accountsPartial({ ...revokeAccounts, invitedBy: invitee.publicKey })
+        .rpc()
+    ).rejects.toThrow("An invite's rent must go back to whoever created it");
+    await program.methods.revokeInvite().accountsPartial(revokeAccounts).rpc();
+    expect(
+      await provider.connection.getAccountInfo(otherInvitePda)
+    ).toBeNull();
+  });
+
+  it("Requires a valid, attested handle to change handles", async () => {
+    const { twitterAccountPda, twitterHandle } = await setupVerifiedAccount(
+      program,
+      provider
+    );
+    const accountsFor = (newHandle: string) => ({
+      twitterAccount: twitterAccountPda,
+      oldHandleIndex: findHandleIndexPDA(twitterHandle, program.programId)[0],
+      newHandleIndex: findHandleIndexPDA(newHandle, program.programId)[0],
+      attestationConfig: null,
+      owner: provider.wallet.publicKey,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+    });
+
+    await expect(
+      program.methods
+        .updateHandle("bad@@handle")
+        .accountsPartial(accountsFor("bad@@handle"))
+        .rpc()
+    ).rejects.toThrow("Invalid Twitter handle format");
+
+    const newHandle = `n${twitterHandle}`;
+    await expect(
+      program.methods
+        .updateHandle(newHandle)
+        .accountsPartial(accountsFor(newHandle))
+        .rpc()
+    ).rejects.toThrow("Missing service attestation");
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.twitterHandle).toBe(twitterHandle);
+    expect(twitterAccount.handleHistory).toHaveLength(0);
+
+    // The reverse lookup still resolves the current handle
+    const handleIndex = await program.account.handleIndex.fetch(
+      findHandleIndexPDA(twitterHandle.toUpperCase(), program.programId)[0]
+    );
+    expect(handleIndex.twitterAccount.toString()).toBe(
+      twitterAccountPda.toString()
+    );
+  });
+
+  it("Moves the handle index on an attested handle change", async () => {
+    const { twitterAccountPda, twitterHandle } = await setupVerifiedAccount(
+      program,
+      provider
+    );
+    const { twitterId } = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    const newHandle = `n${twitterHandle}`;
+    const [oldHandleIndex] = findHandleIndexPDA(
+      twitterHandle,
+      program.programId
+    );
+    const [newHandleIndex] = findHandleIndexPDA(newHandle, program.programId);
+
+    await program.methods
+      .updateHandle(newHandle)
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        oldHandleIndex,
+        newHandleIndex,
+        attestationConfig: null,
+        owner: provider.wallet.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([
+        createHandleAttestation(
+          twitterAccountPda,
+          twitterId,
+          newHandle,
+          loadServiceAuthority()
+        ),
+      ])
+      .rpc();
+
+    const twitterAccount = await program.account.twitterAccount.fetch(
+      twitterAccountPda
+    );
+    expect(twitterAccount.twitterHandle).toBe(newHandle);
+    expect(twitterAccount.handleHistory).toHaveLength(1);
+    expect(twitterAccount.handleHistory[0].handle).toBe(twitterHandle);
+    expect(
+      twitterAccount.handleHistory[0].changedAt.toNumber()
+    ).toBeGreaterThan(0);
+
+    // The old handle is released and the new one resolves to the account
+    expect(await provider.connection.getAccountInfo(oldHandleIndex)).toBeNull();
+    const handleIndex = await program.account.handleIndex.fetch(newHandleIndex);
+    expect(handleIndex.twitterAccount.toString()).toBe(
+      twitterAccountPda.toString()
+    );
+    expect(handleIndex.handle).toBe(newHandle);
+  });
+
+  it("Claims the handle index only for attested handles", async () => {
+    const registerWithHandle = async (twitterHandle: string) => {
+      const twitterId = generateUniqueTwitterId();
+      const [twitterAccountPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("twitter-account"), Buffer.from(twitterId)],
+        program.programId
+      );
+      await program.methods
+        .registerTwitterAccount(twitterId, twitterHandle)
+        .accountsPartial({
+          twitterAccount: twitterAccountPda,
+          owner: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      return { twitterAccountPda, twitterId };
+    };
+    const twitterHandle = generateUniqueHandle(generateUniqueTwitterId());
+    const [handleIndexPda] = findHandleIndexPDA(
+      twitterHandle,
+      program.programId
+    );
+
+    // An unverified registration cannot squat the handle
+    await registerWithHandle(twitterHandle);
+    const rightful = await registerWithHandle(twitterHandle);
+    await verifyTwitterAccount(
+      program,
+      provider,
+      rightful.twitterAccountPda,
+      rightful.twitterId
+    );
+    let handleIndex = await program.account.handleIndex.fetch(handleIndexPda);
+    expect(handleIndex.twitterAccount.toString()).toBe(
+      rightful.twitterAccountPda.toString()
+    );
+
+    // Once the service vouches for another account using the handle, it
+    // takes the index over
+    const successor = await registerWithHandle(twitterHandle);
+    await verifyTwitterAccount(
+      program,
+      provider,
+      successor.twitterAccountPda,
+      successor.twitterId
+    );
+    handleIndex = await program.account.handleIndex.fetch(handleIndexPda);
+    expect(handleIndex.twitterAccount.toString()).toBe(
+      successor.twitterAccountPda.toString()
+    );
+
+    // Deregistering the previous holder leaves the successor's index alone
+    await program.methods
+      .deregisterTwitterAccount()
+      .accountsPartial({
+        twitterAccount: rightful.twitterAccountPda,
+        handleIndex: handleIndexPda,
+        owner: provider.wallet.publicKey,
+        ownerMemberPayer: provider.wallet.publicKey,
+      })
+      .rpc();
+    handleIndex = await program.account.handleIndex.fetch(handleIndexPda);
+    expect(handleIndex.twitterAccount.toString()).toBe(
+      successor.twitterAccountPda.toString()
+    );
   });
 });
 
@@ -511,38 +1498,23 @@ export const setupVerifiedAccount = async (
   provider: anchor.AnchorProvider
 ) => {
   const twitterId = generateUniqueTwitterId();
-  const twitterHandle = "test_handle";
+  const twitterHandle = generateUniqueHandle(twitterId);
 
   const [twitterAccountPda] = PublicKey.findProgramAddressSync(
     [Buffer.from("twitter-account"), Buffer.from(twitterId)],
     program.programId
   );
 
-  const [adminListPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("admin-list"), Buffer.from(twitterId)],
-    program.programId
-  );
-
-  const [creatorListPda] = findCreatorListPDA(twitterId, program.programId);
-
   await program.methods
     .registerTwitterAccount(twitterId, twitterHandle)
     .accountsPartial({
       twitterAccount: twitterAccountPda,
-      adminList: adminListPda,
-      creatorList: creatorListPda,
       owner: provider.wallet.publicKey,
       systemProgram: SystemProgram.programId,
     })
     .rpc();
 
-  await program.methods
-    .verifyTwitterAccount()
-    .accountsPartial({
-      twitterAccount: twitterAccountPda,
-      owner: provider.wallet.publicKey,
-    })
-    .rpc();
+  await verifyTwitterAccount(program, provider, twitterAccountPda, twitterId);
 
-  return { twitterAccountPda, adminListPda };
+  return { twitterAccountPda, twitterHandle };
 };
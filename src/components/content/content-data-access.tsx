@@ -46,15 +46,9 @@ export function useContentActions(twitterId?: string) {
   });
 
   const approveMutation = useMutation({
-    mutationFn: async ({
-      content,
-      author,
-    }: {
-      content: string;
-      author: PublicKey;
-    }) => {
+    mutationFn: async ({ contentPda }: { contentPda: PublicKey }) => {
       if (!publicKey || !twitterId) throw new Error("Not connected");
-      return approveContent(content, author);
+      return approveContent(contentPda);
     },
     onSuccess: () => {
       transactionToast("Content approved successfully");
@@ -63,16 +57,14 @@ export function useContentActions(twitterId?: string) {
 
   const rejectMutation = useMutation({
     mutationFn: async ({
-      content,
-      author,
+      contentPda,
       reason,
     }: {
-      content: string;
-      author: PublicKey;
+      contentPda: PublicKey;
       reason: string;
     }) => {
       if (!publicKey || !twitterId) throw new Error("Not connected");
-      return rejectContent(content, author, reason);
+      return rejectContent(contentPda, reason);
     },
     onSuccess: () => {
       transactionToast("Content rejected successfully");
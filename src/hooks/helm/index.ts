@@ -5,3 +5,4 @@ export * from "./useTwitterAccount";
 export * from "./useContent";
 export * from "./useAdmin";
 export * from "./useCreator";
+export * from "./useMembers";
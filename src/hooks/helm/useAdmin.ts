@@ -4,17 +4,19 @@ import { useCallback, useState } from "react";
 import { useHelm } from "./useHelm";
 import { useWallet } from "@solana/wallet-adapter-react";
 import { PublicKey } from "@solana/web3.js";
-import { findAdminListPDA } from "@/services/helm";
+import { useMembers } from "./useMembers";
+import { ROLE_ADMIN } from "@/services/helm";
 
 export function useAdmin(twitterId?: string) {
-  const { program, instructions, handleTransaction } = useHelm();
+  const { instructions, handleTransaction } = useHelm();
   const { publicKey: currentUserPublicKey } = useWallet();
+  const { getMember, getMembersWithRole, hasRole } = useMembers(twitterId);
 
   /**
    * Add a new admin
    */
   const addAdmin = useCallback(
-    async (newAdmin: PublicKey) => {
+    async (newAdmin: PublicKey, expiresAt?: number) => {
       if (!instructions || !currentUserPublicKey || !twitterId) {
         throw new Error("Missing required parameters");
       }
@@ -23,7 +25,8 @@ export function useAdmin(twitterId?: string) {
         const tx = instructions.addAdmin(
           twitterId,
           newAdmin,
-          currentUserPublicKey
+          currentUserPublicKey,
+          expiresAt
         );
 
         await handleTransaction(tx.rpc(), {
@@ -34,30 +37,39 @@ export function useAdmin(twitterId?: string) {
           },
         });
 
-        // Return the updated admin list
-        const [adminListPda] = findAdminListPDA(twitterId);
-        return await program?.account.adminList.fetch(adminListPda);
+        // Return the admin's membership record
+        return await getMember(newAdmin);
       } catch (err) {
         throw err;
       }
     },
-    [instructions, currentUserPublicKey, twitterId, program, handleTransaction]
+    [
+      instructions,
+      currentUserPublicKey,
+      twitterId,
+      handleTransaction,
+      getMember,
+    ]
   );
 
   /**
-   * Remove an admin
+   * Remove an admin, dropping their approvals from any open content passed along
    */
   const removeAdmin = useCallback(
-    async (adminToRemove: PublicKey) => {
+    async (adminToRemove: PublicKey, openContent: PublicKey[] = []) => {
       if (!instructions || !currentUserPublicKey || !twitterId) {
         throw new Error("Missing required parameters");
       }
 
       try {
+        // The record's rent goes back to whoever paid for it once no role is left
+        const member = await getMember(adminToRemove);
         const tx = instructions.removeAdmin(
           twitterId,
           adminToRemove,
-          currentUserPublicKey
+          currentUserPublicKey,
+          member?.payer ?? null,
+          openContent
         );
         await handleTransaction(tx.rpc(), {
           onSuccess: () => console.log("Admin removed successfully"),
@@ -67,32 +79,28 @@ export function useAdmin(twitterId?: string) {
           },
         });
 
-        // Return the updated admin list
-        const [adminListPda] = findAdminListPDA(twitterId);
-        return await program?.account.adminList.fetch(adminListPda);
+        // Return what is left of the membership, if anything
+        return await getMember(adminToRemove);
       } catch (err) {
         throw err;
       }
     },
-    [instructions, currentUserPublicKey, twitterId, program, handleTransaction]
+    [
+      instructions,
+      currentUserPublicKey,
+      twitterId,
+      handleTransaction,
+      getMember,
+    ]
   );
 
   /**
-   * Get current admin list
+   * Get the account's current admins
    */
-  const getAdminList = useCallback(async () => {
-    if (!program || !twitterId) {
-      throw new Error("Missing required parameters");
-    }
-
-    try {
-      const [adminListPda] = findAdminListPDA(twitterId);
-      return await program.account.adminList.fetch(adminListPda);
-    } catch (err) {
-      console.error("Failed to fetch admin list:", err);
-      throw err;
-    }
-  }, [program, twitterId]);
+  const getAdminList = useCallback(
+    () => getMembersWithRole(ROLE_ADMIN),
+    [getMembersWithRole]
+  );
 
   /**
    * Check if an address is an admin
@@ -100,16 +108,13 @@ export function useAdmin(twitterId?: string) {
   const isAdmin = useCallback(
     async (address: PublicKey) => {
       try {
-        const adminList = await getAdminList();
-        return adminList.admins.some(
-          (admin) => admin.toString() === address.toString()
-        );
+        return await hasRole(address, ROLE_ADMIN);
       } catch (err) {
         console.error("Failed to check admin status:", err);
         return false;
       }
     },
-    [getAdminList]
+    [hasRole]
   );
 
   return {
@@ -7,12 +7,13 @@ import { useWallet } from "@solana/wallet-adapter-react";
 import { PublicKey } from "@solana/web3.js";
 import {
   ContentType,
-  createContentHash,
   findContentPDA,
+  findTwitterAccountPDA,
 } from "@/services/helm";
 
 export function useContent(twitterId?: string) {
-  const { instructions, handleTransaction, getContent } = useHelm();
+  const { instructions, handleTransaction, getContent, getTwitterAccount } =
+    useHelm();
   const { publicKey } = useWallet();
 
   /**
@@ -29,12 +30,18 @@ export function useContent(twitterId?: string) {
       }
 
       try {
+        // New content takes the next number in the account's content sequence
+        const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
+        const { contentCount } = await getTwitterAccount(twitterAccountPda);
+        const [contentPda] = findContentPDA(twitterAccountPda, contentCount);
+
         console.log(" composing transactions");
         const tx = instructions.submitForApproval(
           twitterId,
           content,
           contentType,
           publicKey,
+          contentCount,
           scheduledFor
         );
 
@@ -52,35 +59,38 @@ export function useContent(twitterId?: string) {
         }
 
         // Return the new content account
-        const contentHash = createContentHash(content);
-        const [contentPda] = findContentPDA(
-          new PublicKey(twitterId),
-          publicKey,
-          contentHash
-        );
         return await getContent(contentPda);
       } catch (err) {
         throw err;
       }
     },
-    [instructions, publicKey, twitterId, handleTransaction, getContent]
+    [
+      instructions,
+      publicKey,
+      twitterId,
+      handleTransaction,
+      getContent,
+      getTwitterAccount,
+    ]
   );
 
   /**
    * Approve content
    */
   const approveContent = useCallback(
-    async (content: string, author: PublicKey) => {
+    async (contentPda: PublicKey) => {
       if (!instructions || !publicKey || !twitterId) {
         throw new Error("Missing required parameters");
       }
 
       try {
+        // Earlier approvers' records are passed so only current admins count
+        const { approvals } = await getContent(contentPda);
         const tx = instructions.approveContent(
           twitterId,
-          content,
-          author,
-          publicKey
+          contentPda,
+          publicKey,
+          approvals
         );
 
         await handleTransaction(tx.rpc(), {
@@ -92,12 +102,6 @@ export function useContent(twitterId?: string) {
         });
 
         // Return the updated content account
-        const contentHash = createContentHash(content);
-        const [contentPda] = findContentPDA(
-          new PublicKey(twitterId),
-          author,
-          contentHash
-        );
         return await getContent(contentPda);
       } catch (err) {
         throw err;
@@ -110,7 +114,7 @@ export function useContent(twitterId?: string) {
    * Reject content
    */
   const rejectContent = useCallback(
-    async (content: string, author: PublicKey, reason: string) => {
+    async (contentPda: PublicKey, reason: string) => {
       if (!instructions || !publicKey || !twitterId) {
         throw new Error("Missing required parameters");
       }
@@ -118,8 +122,7 @@ export function useContent(twitterId?: string) {
       try {
         const tx = await instructions.rejectContent(
           twitterId,
-          content,
-          author,
+          contentPda,
           publicKey,
           reason
         );
@@ -133,12 +136,6 @@ export function useContent(twitterId?: string) {
         });
 
         // Return the updated content account
-        const contentHash = createContentHash(content);
-        const [contentPda] = findContentPDA(
-          new PublicKey(twitterId),
-          author,
-          contentHash
-        );
         return await getContent(contentPda);
       } catch (err) {
         throw err;
@@ -151,34 +148,23 @@ export function useContent(twitterId?: string) {
    * Cancel content
    */
   const cancelContent = useCallback(
-    async (content: string, author: PublicKey, reason: string) => {
+    async (contentPda: PublicKey) => {
       if (!instructions || !publicKey || !twitterId) {
         throw new Error("Missing required parameters");
       }
 
       try {
-        const tx = instructions.cancelContent(
-          twitterId,
-          content,
-          author,
-          publicKey
-        );
+        const tx = instructions.cancelContent(twitterId, contentPda, publicKey);
 
         await handleTransaction(tx.rpc(), {
-          onSuccess: () => console.log("Content rejected successfully"),
+          onSuccess: () => console.log("Content canceled successfully"),
           onError: (error) => {
-            console.error("Failed to reject content:", error);
+            console.error("Failed to cancel content:", error);
             throw error;
           },
         });
 
         // Return the updated content account
-        const contentHash = createContentHash(content);
-        const [contentPda] = findContentPDA(
-          new PublicKey(twitterId),
-          author,
-          contentHash
-        );
         return await getContent(contentPda);
       } catch (err) {
         throw err;
@@ -3,24 +3,30 @@
 import { useCallback, useState } from "react";
 import { useHelm } from "./useHelm";
 import { useWallet } from "@solana/wallet-adapter-react";
-import { findCreatorListPDA } from "../../services/helm";
+import { useMembers } from "./useMembers";
+import { ROLE_CREATOR } from "../../services/helm";
 import { PublicKey } from "@solana/web3.js";
 
 export function useCreator(twitterId?: string) {
-  const { program, instructions, handleTransaction } = useHelm();
+  const { instructions, handleTransaction } = useHelm();
   const { publicKey } = useWallet();
+  const { getMember, getMembersWithRole, hasRole } = useMembers(twitterId);
 
   /**
    * Add a new creator
    */
   const addCreator = useCallback(
-    async (newCreator: PublicKey) => {
+    async (newCreator: PublicKey, expiresAt?: number) => {
       if (!instructions || !publicKey || !twitterId) {
         throw new Error("Missing required parameters");
       }
       try {
-        // TODO: flesh out the main instructions;
-        const tx = instructions.addCreator(twitterId, newCreator);
+        const tx = instructions.addCreator(
+          twitterId,
+          newCreator,
+          publicKey,
+          expiresAt
+        );
         await handleTransaction(tx.rpc(), {
           onSuccess: () => console.log("Creator added successfully"),
           onError: (error) => {
@@ -28,14 +34,13 @@ export function useCreator(twitterId?: string) {
             throw error;
           },
         });
-        // // Return the updated creator list
-        const [creatorListPda] = findCreatorListPDA(twitterId);
-        return await program?.account.creatorList.fetch(creatorListPda);
+        // Return the creator's membership record
+        return await getMember(newCreator);
       } catch (err) {
         throw err;
       }
     },
-    [instructions, publicKey, twitterId, program, handleTransaction]
+    [instructions, publicKey, twitterId, handleTransaction, getMember]
   );
 
   /**
@@ -48,41 +53,38 @@ export function useCreator(twitterId?: string) {
       }
 
       try {
-        const tx = instructions.removeCreator(twitterId, creatorToRemove);
+        // The record's rent goes back to whoever paid for it once no role is left
+        const member = await getMember(creatorToRemove);
+        const tx = instructions.removeCreator(
+          twitterId,
+          creatorToRemove,
+          publicKey,
+          member?.payer ?? null
+        );
         await handleTransaction(tx.rpc(), {
           onSuccess: () => console.log("Creator removed successfully"),
           onError: (error) => {
-            console.error("Failed to  creator:", error);
+            console.error("Failed to remove creator:", error);
             throw error;
           },
         });
 
-        // Return the updated creator list
-        const [creatorListPda] = findCreatorListPDA(twitterId);
-        return await program?.account.creatorList.fetch(creatorListPda);
+        // Return what is left of the membership, if anything
+        return await getMember(creatorToRemove);
       } catch (err) {
         throw err;
       }
     },
-    [instructions, publicKey, twitterId, program, handleTransaction]
+    [instructions, publicKey, twitterId, handleTransaction, getMember]
   );
 
   /**
-   * Get current creator list
+   * Get the account's current creators
    */
-  const getCreatorList = useCallback(async () => {
-    if (!program || !twitterId) {
-      throw new Error("Missing required parameters");
-    }
-
-    try {
-      const [creatorListPda] = findCreatorListPDA(twitterId);
-      return await program.account.creatorList.fetch(creatorListPda);
-    } catch (err) {
-      console.error("Failed to fetch creator list:", err);
-      throw err;
-    }
-  }, [program, twitterId]);
+  const getCreatorList = useCallback(
+    () => getMembersWithRole(ROLE_CREATOR),
+    [getMembersWithRole]
+  );
 
   /**
    * Check if an address is a creator
@@ -90,16 +92,13 @@ export function useCreator(twitterId?: string) {
   const isCreator = useCallback(
     async (address: PublicKey) => {
       try {
-        const creatorList = await getCreatorList();
-        return creatorList.creators.some(
-          (creator) => creator.toString() === address.toString()
-        );
+        return await hasRole(address, ROLE_CREATOR);
       } catch (err) {
         console.error("Failed to check creator status:", err);
         return false;
       }
     },
-    [getCreatorList]
+    [hasRole]
   );
 
   return {
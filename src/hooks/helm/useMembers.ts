@@ -0,0 +1,94 @@
+// File: src/hooks/helm/useMembers.ts
+
+import { useCallback } from "react";
+import { useHelm } from "./useHelm";
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { findMemberPDA, findTwitterAccountPDA } from "@/services/helm";
+
+// A membership only counts while it belongs to the account's current epoch and
+// has not lapsed; the program treats records from before a reclaim as gone
+const isCurrent = (
+  member: { epoch: number; expiresAt: BN | null },
+  memberEpoch: number
+) =>
+  member.epoch === memberEpoch &&
+  (!member.expiresAt || member.expiresAt.toNumber() > Date.now() / 1000);
+
+export function useMembers(twitterId?: string) {
+  const { program, getTwitterAccount } = useHelm();
+
+  /**
+   * Fetch a wallet's membership record, or null if it has none
+   */
+  const getMember = useCallback(
+    async (wallet: PublicKey) => {
+      if (!program || !twitterId) {
+        throw new Error("Missing required parameters");
+      }
+
+      const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
+      const [memberPda] = findMemberPDA(twitterAccountPda, wallet);
+      return await program.account.member.fetchNullable(memberPda);
+    },
+    [program, twitterId]
+  );
+
+  /**
+   * Fetch every current member holding `role`
+   */
+  const getMembersWithRole = useCallback(
+    async (role: number) => {
+      if (!program || !twitterId) {
+        throw new Error("Missing required parameters");
+      }
+
+      try {
+        const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
+        const { memberEpoch } = await getTwitterAccount(twitterAccountPda);
+
+        // Records start with their Twitter account, after the discriminator
+        const members = await program.account.member.all([
+          { memcmp: { offset: 8, bytes: twitterAccountPda.toBase58() } },
+        ]);
+        return members
+          .map(({ account }) => account)
+          .filter(
+            (member) =>
+              (member.roles & role) !== 0 && isCurrent(member, memberEpoch)
+          );
+      } catch (err) {
+        console.error("Failed to fetch members:", err);
+        throw err;
+      }
+    },
+    [program, twitterId, getTwitterAccount]
+  );
+
+  /**
+   * Check whether a wallet currently holds `role`
+   */
+  const hasRole = useCallback(
+    async (wallet: PublicKey, role: number) => {
+      if (!twitterId) {
+        throw new Error("Missing required parameters");
+      }
+
+      const member = await getMember(wallet);
+      if (!member || (member.roles & role) === 0) {
+        return false;
+      }
+
+      const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
+      const { memberEpoch } = await getTwitterAccount(twitterAccountPda);
+      return isCurrent(member, memberEpoch);
+    },
+    [twitterId, getMember, getTwitterAccount]
+  );
+
+  return {
+    getMember,
+    getMembersWithRole,
+    hasRole,
+  };
+}
@@ -3,6 +3,7 @@
 import { useCallback, useEffect, useState } from "react";
 import { useHelm } from "./useHelm";
 import { useWallet } from "@solana/wallet-adapter-react";
+import { TransactionInstruction } from "@solana/web3.js";
 import { findTwitterAccountPDA } from "../../services/helm";
 import { TwitterAccount } from "../../services/helm";
 
@@ -54,12 +55,25 @@ export function useTwitterAccount(twitterId: string | undefined) {
     [instructions, publicKey, handleTransaction, getTwitterAccount]
   );
 
+  /**
+   * Verify a registered account with the attestation the service signed once it
+   * confirmed the proof tweet
+   */
   const verifyAccount = useCallback(
-    async (twitterId: string) => {
+    async (
+      twitterId: string,
+      twitterHandle: string,
+      attestation: TransactionInstruction
+    ) => {
       if (!instructions || !publicKey) throw new Error("Not connected");
 
       try {
-        const tx = instructions.verifyTwitterAccount(twitterId, publicKey);
+        const tx = instructions.verifyTwitterAccount(
+          twitterId,
+          twitterHandle,
+          publicKey,
+          attestation
+        );
 
         const result = await handleTransaction(tx.rpc(), {
           onSuccess: () => console.log("Twitter account verified successfully"),
@@ -1,5 +1,6 @@
 // File: src//services/helm/accounts.ts
 
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { Buffer } from "buffer";
 import { HELM_PROGRAM_ID } from "./program";
@@ -17,25 +18,29 @@ export function findTwitterAccountPDA(twitterId: string): [PublicKey, number] {
 }
 
 /**
- * Find Admin List PDA
- * @param twitterId - Twitter account ID
+ * Find Handle Index PDA, the reverse lookup from a verified handle to its account
+ * @param twitterHandle - Twitter handle, matched case-insensitively
  * @returns [PublicKey, number] - PDA and bump
  */
-export function findAdminListPDA(twitterId: string): [PublicKey, number] {
+export function findHandleIndexPDA(twitterHandle: string): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("admin-list"), Buffer.from(twitterId)],
+    [Buffer.from("handle-index"), Buffer.from(twitterHandle.toLowerCase())],
     HELM_PROGRAM_ID
   );
 }
 
 /**
- * Find Creator List PDA
- * @param twitterId - Twitter account ID
+ * Find Member PDA, holding one wallet's roles on a Twitter account
+ * @param twitterAccount - Twitter account public key
+ * @param wallet - Member wallet public key
  * @returns [PublicKey, number] - PDA and bump
  */
-export function findCreatorListPDA(twitterId: string): [PublicKey, number] {
+export function findMemberPDA(
+  twitterAccount: PublicKey,
+  wallet: PublicKey
+): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("creator-list"), Buffer.from(twitterId)],
+    [Buffer.from("member"), twitterAccount.toBuffer(), wallet.toBuffer()],
     HELM_PROGRAM_ID
   );
 }
@@ -43,21 +48,18 @@ export function findCreatorListPDA(twitterId: string): [PublicKey, number] {
 /**
  * Find Content PDA
  * @param twitterAccount - Twitter account public key
- * @param author - Content author public key
- * @param contentHash - Content hash as Buffer
+ * @param sequence - Per-account sequence number; new content takes the account's contentCount
  * @returns [PublicKey, number] - PDA and bump
  */
 export function findContentPDA(
   twitterAccount: PublicKey,
-  author: PublicKey,
-  contentHash: Buffer
+  sequence: BN | number
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from("content"),
       twitterAccount.toBuffer(),
-      author.toBuffer(),
-      contentHash,
+      new BN(sequence).toArrayLike(Buffer, "le", 8),
     ],
     HELM_PROGRAM_ID
   );
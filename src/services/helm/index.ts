@@ -9,8 +9,7 @@ export * from "./errors";
 // Re-export specific types and utilities that will be commonly used
 export type {
   TwitterAccount,
-  AdminList,
-  CreatorList,
+  Member,
   Content,
   ContentType,
 } from "./types";
@@ -1,11 +1,16 @@
 // File: src//services/helm/instructions.ts
 
 import { BN, Program } from "@coral-xyz/anchor";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  TransactionInstruction,
+} from "@solana/web3.js";
 import {
   findTwitterAccountPDA,
-  findAdminListPDA,
-  findCreatorListPDA,
+  findHandleIndexPDA,
+  findMemberPDA,
   findContentPDA,
   createContentHash,
 } from "./accounts";
@@ -28,15 +33,13 @@ export class HelmInstructions {
     owner: PublicKey
   ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
-    const [creatorListPda] = findCreatorListPDA(twitterId);
+    const [ownerMemberPda] = findMemberPDA(twitterAccountPda, owner);
 
     return this.program.methods
       .registerTwitterAccount(twitterId, twitterHandle)
       .accountsPartial({
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
-        creatorList: creatorListPda,
+        ownerMember: ownerMemberPda,
         owner,
         systemProgram: SystemProgram.programId,
       });
@@ -44,14 +47,30 @@ export class HelmInstructions {
 
   /**
    * Verify a Twitter account
+   * @param attestation - ed25519 instruction with the service authority's
+   * signature over the account, Twitter ID, owner and handle, issued by the
+   * proof-of-tweet flow
    */
-  verifyTwitterAccount(twitterId: string, owner: PublicKey) {
+  verifyTwitterAccount(
+    twitterId: string,
+    twitterHandle: string,
+    owner: PublicKey,
+    attestation: TransactionInstruction
+  ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
+    const [handleIndexPda] = findHandleIndexPDA(twitterHandle);
 
-    return this.program.methods.verifyTwitterAccount().accountsPartial({
-      twitterAccount: twitterAccountPda,
-      owner,
-    });
+    return this.program.methods
+      .verifyTwitterAccount()
+      .accountsPartial({
+        twitterAccount: twitterAccountPda,
+        handleIndex: handleIndexPda,
+        attestationConfig: null,
+        owner,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([attestation]);
   }
 
   /**
@@ -60,27 +79,57 @@ export class HelmInstructions {
 
   /**
    * Add an admin to a Twitter account
+   * @param expiresAt - Optional Unix timestamp at which the membership lapses
    */
-  addAdmin(twitterId: string, newAdmin: PublicKey, owner: PublicKey) {
+  addAdmin(
+    twitterId: string,
+    newAdmin: PublicKey,
+    owner: PublicKey,
+    expiresAt?: number
+  ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
+    const [memberPda] = findMemberPDA(twitterAccountPda, newAdmin);
 
-    return this.program.methods.addAdmin(newAdmin).accountsPartial({
-      adminList: adminListPda,
-      twitterAccount: twitterAccountPda,
-      owner,
-    });
+    return this.program.methods
+      .addAdmin(newAdmin, expiresAt ? new BN(expiresAt) : null)
+      .accountsPartial({
+        member: memberPda,
+        twitterAccount: twitterAccountPda,
+        owner,
+        memberPayer: null,
+      });
   }
 
-  removeAdmin(twitterId: string, adminToRemove: PublicKey, owner: PublicKey) {
-    //
+  /**
+   * Remove an admin
+   * @param memberPayer - The member record's payer, refunded if no role is left
+   * @param openContent - Open content to drop this admin's approvals from
+   */
+  removeAdmin(
+    twitterId: string,
+    adminToRemove: PublicKey,
+    owner: PublicKey,
+    memberPayer: PublicKey | null,
+    openContent: PublicKey[] = []
+  ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
-    return this.program?.methods.removeAdmin(adminToRemove).accountsPartial({
-      adminList: adminListPda,
-      twitterAccount: twitterAccountPda,
-      owner,
-    });
+    const [memberPda] = findMemberPDA(twitterAccountPda, adminToRemove);
+
+    return this.program.methods
+      .removeAdmin(adminToRemove)
+      .accountsPartial({
+        member: memberPda,
+        twitterAccount: twitterAccountPda,
+        owner,
+        memberPayer,
+      })
+      .remainingAccounts(
+        openContent.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      );
   }
 
   /**
@@ -88,29 +137,46 @@ export class HelmInstructions {
    */
 
   /**
-   * Creator
+   * Add a creator to a Twitter account
+   * @param expiresAt - Optional Unix timestamp at which the membership lapses
    */
-  addCreator(twitterId: string, creator: PublicKey) {
+  addCreator(
+    twitterId: string,
+    creator: PublicKey,
+    owner: PublicKey,
+    expiresAt?: number
+  ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
-    const [creatorListPda] = findCreatorListPDA(twitterId);
+    const [memberPda] = findMemberPDA(twitterAccountPda, creator);
 
-    return this.program.methods.addCreator(creator).accountsPartial({
-      creatorList: creatorListPda,
-      twitterAccount: twitterAccountPda,
-      owner: adminListPda,
-    });
+    return this.program.methods
+      .addCreator(creator, expiresAt ? new BN(expiresAt) : null)
+      .accountsPartial({
+        member: memberPda,
+        twitterAccount: twitterAccountPda,
+        owner,
+        memberPayer: null,
+      });
   }
 
-  removeCreator(twitterId: string, creator: PublicKey) {
+  /**
+   * Remove a creator
+   * @param memberPayer - The member record's payer, refunded if no role is left
+   */
+  removeCreator(
+    twitterId: string,
+    creator: PublicKey,
+    owner: PublicKey,
+    memberPayer: PublicKey | null
+  ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
-    const [creatorListPda] = findCreatorListPDA(twitterId);
+    const [memberPda] = findMemberPDA(twitterAccountPda, creator);
 
     return this.program.methods.removeCreator(creator).accountsPartial({
-      creatorList: creatorListPda,
+      member: memberPda,
       twitterAccount: twitterAccountPda,
-      owner: adminListPda,
+      owner,
+      memberPayer,
     });
   }
 
@@ -120,18 +186,20 @@ export class HelmInstructions {
 
   /**
    * Submit content for approval
+   * @param sequence - The Twitter account's current contentCount
    */
   submitForApproval(
     twitterId: string,
     content: string,
     contentType: ContentType,
     author: PublicKey,
+    sequence: BN | number,
     scheduledFor?: number
   ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
+    const [memberPda] = findMemberPDA(twitterAccountPda, author);
     const contentHash = createContentHash(content);
-    const [contentPda] = findContentPDA(twitterAccountPda, author, contentHash);
+    const [contentPda] = findContentPDA(twitterAccountPda, sequence);
 
     return this.program.methods
       .submitForApproval(
@@ -142,7 +210,7 @@ export class HelmInstructions {
       .accountsPartial({
         content: contentPda,
         twitterAccount: twitterAccountPda,
-        adminList: adminListPda,
+        member: memberPda,
         authority: author,
         systemProgram: SystemProgram.programId,
       });
@@ -150,24 +218,33 @@ export class HelmInstructions {
 
   /**
    * Approve content
+   * @param approvers - Wallets that already approved, whose Member PDAs let the
+   * program count only approvals from current admins
    */
   approveContent(
     twitterId: string,
-    content: string,
-    author: PublicKey,
-    authority: PublicKey
+    contentPda: PublicKey,
+    authority: PublicKey,
+    approvers: PublicKey[] = []
   ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
-    const contentHash = createContentHash(content);
-    const [contentPda] = findContentPDA(twitterAccountPda, author, contentHash);
+    const [memberPda] = findMemberPDA(twitterAccountPda, authority);
 
-    return this.program.methods.approveContent().accountsPartial({
-      content: contentPda,
-      twitterAccount: twitterAccountPda,
-      adminList: adminListPda,
-      authority,
-    });
+    return this.program.methods
+      .approveContent()
+      .accountsPartial({
+        content: contentPda,
+        twitterAccount: twitterAccountPda,
+        member: memberPda,
+        authority,
+      })
+      .remainingAccounts(
+        approvers.map((approver) => ({
+          pubkey: findMemberPDA(twitterAccountPda, approver)[0],
+          isSigner: false,
+          isWritable: false,
+        }))
+      );
   }
 
   /**
@@ -175,20 +252,17 @@ export class HelmInstructions {
    */
   rejectContent(
     twitterId: string,
-    content: string,
-    author: PublicKey,
+    contentPda: PublicKey,
     authority: PublicKey,
     reason: string
   ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
-    const contentHash = createContentHash(content);
-    const [contentPda] = findContentPDA(twitterAccountPda, author, contentHash);
+    const [memberPda] = findMemberPDA(twitterAccountPda, authority);
 
     return this.program.methods.rejectContent(reason).accountsPartial({
       content: contentPda,
       twitterAccount: twitterAccountPda,
-      adminList: adminListPda,
+      member: memberPda,
       authority,
     });
   }
@@ -198,19 +272,16 @@ export class HelmInstructions {
    */
   cancelContent(
     twitterId: string,
-    content: string,
-    author: PublicKey,
+    contentPda: PublicKey,
     authority: PublicKey
   ) {
     const [twitterAccountPda] = findTwitterAccountPDA(twitterId);
-    const [adminListPda] = findAdminListPDA(twitterId);
-    const contentHash = createContentHash(content);
-    const [contentPda] = findContentPDA(twitterAccountPda, author, contentHash);
+    const [memberPda] = findMemberPDA(twitterAccountPda, authority);
 
     return this.program.methods.cancelContent().accountsPartial({
       content: contentPda,
       twitterAccount: twitterAccountPda,
-      adminList: adminListPda,
+      member: memberPda,
       authority,
     });
   }
@@ -32,22 +32,29 @@ export interface TwitterAccount {
   bump: number;
 }
 
-export interface AdminList {
-  twitterAccount: PublicKey;
-  admins: PublicKey[];
-  authority: PublicKey;
-  bump: number;
-}
+// Role bits stored in Member.roles
+export const ROLE_OWNER = 1 << 0;
+export const ROLE_ADMIN = 1 << 1;
+export const ROLE_CREATOR = 1 << 2;
+export const ROLE_PUBLISHER = 1 << 3;
+export const ROLE_VIEWER = 1 << 4;
 
-export interface CreatorList {
+export interface Member {
   twitterAccount: PublicKey;
-  creators: PublicKey[];
-  authority: PublicKey;
+  wallet: PublicKey;
+  roles: number;
+  // Roles only count while this matches the Twitter account's memberEpoch
+  epoch: number;
+  addedBy: PublicKey;
+  addedAt: number;
+  expiresAt: number | null;
+  payer: PublicKey;
   bump: number;
 }
 
 export interface Content {
   twitterAccount: PublicKey;
+  sequence: number;
   author: PublicKey;
   contentType: ContentType;
   contentHash: number[];